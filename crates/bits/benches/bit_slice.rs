@@ -256,6 +256,25 @@ pub fn bench_div(c: &mut Criterion) {
         },
     );
 
+    let max_8 = &[usize::MAX; 8];
+    let divisor = &[usize::MAX, 1, usize::MAX];
+
+    c.benchmark_group("BitSliceExt::div_long (multi-element)")
+        .bench_function(
+            BenchmarkId::new(
+                "BitwiseDiv::div_long",
+                "[usize::MAX; 8], [usize::MAX, 1, usize::MAX]",
+            ),
+            |b| b.iter(|| BitwiseDiv::div_long(black_box(max_8), black_box(divisor))),
+        )
+        .bench_function(
+            BenchmarkId::new(
+                "ElementDiv::div_long",
+                "[usize::MAX; 8], [usize::MAX, 1, usize::MAX]",
+            ),
+            |b| b.iter(|| ElementDiv::div_long(black_box(max_8), black_box(divisor))),
+        );
+
     /*
     c.benchmark_group("BitSlice::div*")
         .bench_with_input(
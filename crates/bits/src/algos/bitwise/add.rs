@@ -1,24 +1,24 @@
-use crate::bit_slice::{BitSliceExt, BitVecExt};
-#[cfg(feature = "std")]
+use crate::bit_slice::{BitSliceExt, BitSliceRead, BitVecExt};
+#[cfg(feature = "alloc")]
 use alloc::{vec, vec::Vec};
 use core::hint::unreachable_unchecked;
 use numeric_traits::identity::Zero;
 
 pub trait BitwiseAdd: BitSliceExt {
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     /// Add two slices, implemented as a bitwise add-and-carry
     fn add<T>(left: &Self, right: &T) -> Vec<Self::Bit>
     where
-        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
-        let len = usize::max(left.len(), right.len());
-        let bit_len = usize::max(left.bit_len(), right.bit_len());
+        let len = usize::max(left.len(), right.elem_len());
+        let bit_len = usize::max(left.bit_len(), right.read_bit_len());
         let mut out = vec![Self::Bit::zero(); len];
 
         let mut carry = false;
         for idx in 0..=bit_len {
             let l = u8::from(left.get_bit_opt(idx).unwrap_or(false));
-            let r = u8::from(right.get_bit_opt(idx).unwrap_or(false));
+            let r = u8::from(right.read_bit(idx).unwrap_or(false));
 
             let c = if carry {
                 carry = false;
@@ -1,26 +1,38 @@
 use crate::algos::{ElementCmp, ElementShl, ElementSub};
-use crate::bit_slice::BitSliceExt;
-#[cfg(feature = "std")]
+use crate::bit_slice::{BitLike, BitSliceExt};
+#[cfg(feature = "alloc")]
 use alloc::{vec, vec::Vec};
 use numeric_traits::identity::Zero;
 
+/// Shift the next bit of the numerator into the remainder, returning whether a bit was shifted out
+/// of the top. If it was, the remainder is larger than any divisor that fits in the slice, and the
+/// wrapping subtraction that follows will bring it back into range.
+fn shl_remainder<B: BitLike>(remainder: &mut [B], bit: bool) -> bool {
+    let high = remainder
+        .bit_len()
+        .checked_sub(1)
+        .is_some_and(|top| remainder.get_bit(top));
+    ElementShl::shl_wrapping(remainder, 1);
+    remainder.set_bit(0, bit);
+    high
+}
+
 pub trait BitwiseDiv: BitSliceExt {
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     /// Divide two slices, implemented as bitwise long division
     fn div_long<T>(num: &Self, div: &T) -> (Vec<Self::Bit>, Vec<Self::Bit>)
     where
         T: ?Sized + BitSliceExt<Bit = Self::Bit>,
     {
         let len = usize::max(num.len(), div.len());
-        let bit_len = usize::max(num.bit_len(), div.bit_len());
 
         let mut quotient = vec![Self::Bit::zero(); len];
         let mut remainder = vec![Self::Bit::zero(); len];
 
-        for idx in (0..bit_len).rev() {
-            ElementShl::shl_wrapping(&mut remainder, 1);
-            remainder.set_bit(0, num.get_bit(idx));
-            if ElementCmp::cmp(&remainder, div).is_ge() {
+        for idx in (0..num.bit_len()).rev() {
+            if shl_remainder(&mut remainder, num.get_bit(idx))
+                || ElementCmp::cmp(&remainder, div).is_ge()
+            {
                 // Subtract will never overflow
                 ElementSub::sub_wrapping(&mut remainder, div);
                 quotient.set_bit(idx, true);
@@ -39,11 +51,9 @@ pub trait BitwiseDiv: BitSliceExt {
     where
         T: ?Sized + BitSliceExt<Bit = Self::Bit>,
     {
-        let bit_len = usize::max(num.bit_len(), div.bit_len());
-        for idx in (0..bit_len).rev() {
-            ElementShl::shl_wrapping(remainder, 1);
-            remainder.set_bit(0, num.get_bit(idx));
-            if ElementCmp::cmp(remainder, div).is_ge() {
+        for idx in (0..num.bit_len()).rev() {
+            if shl_remainder(remainder, num.get_bit(idx)) || ElementCmp::cmp(remainder, div).is_ge()
+            {
                 // Subtract will never overflow
                 ElementSub::sub_wrapping(remainder, div);
                 num.set_bit(idx, true);
@@ -93,12 +103,9 @@ pub trait BitwiseDiv: BitSliceExt {
     where
         T: ?Sized + BitSliceExt<Bit = Self::Bit>,
     {
-        let bit_len = usize::max(num.bit_len(), div.bit_len());
-
-        for idx in (0..bit_len).rev() {
-            ElementShl::shl_wrapping(remainder, 1);
-            remainder.set_bit(0, num.get_bit(idx));
-            if ElementCmp::cmp(remainder, div).is_ge() {
+        for idx in (0..num.bit_len()).rev() {
+            if shl_remainder(remainder, num.get_bit(idx)) || ElementCmp::cmp(remainder, div).is_ge()
+            {
                 // Subtract will never overflow
                 ElementSub::sub_wrapping(remainder, div);
             }
@@ -192,6 +199,37 @@ mod tests {
         assert_eq!(BitwiseDiv::div_long(slice5, slice6).1, &[0b01, 0b0]);
     }
 
+    #[test]
+    fn test_large_divisor() {
+        // Remainder gets shifted past the top of the slice before being subtracted from
+        let (quot, rem) = BitwiseDiv::div_long(&[0xFFu8], &[0xC0]);
+        assert_eq!((quot.as_slice(), rem.as_slice()), (&[1u8][..], &[0x3F][..]));
+
+        let (quot, rem) = BitwiseDiv::div_long(&[0xFFu8, 0xFF], &[0x01, 0xC0]);
+        assert_eq!(
+            (quot.as_slice(), rem.as_slice()),
+            (&[1u8, 0][..], &[0xFE, 0x3F][..])
+        );
+
+        let mut data = [0xFFu8];
+        assert_eq!(
+            BitwiseDiv::div_long_wrapping(&mut data, &[0xC0], &mut [0]),
+            &[1]
+        );
+        let mut data = [0xFFu8];
+        assert_eq!(
+            BitwiseDiv::rem_long_wrapping(&mut data, &[0xC0], &mut [0]),
+            &[0x3F]
+        );
+
+        // Divisor longer than the numerator
+        let (quot, rem) = BitwiseDiv::div_long(&[5u8], &[0, 1]);
+        assert_eq!(
+            (quot.as_slice(), rem.as_slice()),
+            (&[0u8, 0][..], &[5, 0][..])
+        );
+    }
+
     #[test]
     fn test_div_wrapping() {
         let mut data = [0b10u8];
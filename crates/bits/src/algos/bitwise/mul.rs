@@ -1,22 +1,22 @@
 use crate::algos::{ElementAdd, ElementShl};
-use crate::bit_slice::BitSliceExt;
-#[cfg(feature = "std")]
+use crate::bit_slice::{BitSliceExt, BitSliceRead};
+#[cfg(feature = "alloc")]
 use alloc::{vec, vec::Vec};
 use numeric_traits::identity::Zero;
 
 pub trait BitwiseMul: BitSliceExt {
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     /// Multiply two slices, implemented as a bitwise shift-and-add
     fn mul<T>(left: &Self, right: &T) -> Vec<Self::Bit>
     where
-        T: BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
-        let len = usize::max(left.len(), right.len());
+        let len = usize::max(left.len(), right.elem_len());
         let mut new_self = ElementShl::shl(left, 0);
         let mut out = vec![Self::Bit::zero(); len * 2];
 
-        for idx in 0..right.bit_len() {
-            let r = right.get_bit(idx);
+        for idx in 0..right.read_bit_len() {
+            let r = right.read_bit(idx).unwrap_or(false);
             if r {
                 out = ElementAdd::add(&out, &new_self);
             }
@@ -1,10 +1,10 @@
 use crate::bit_slice::{BitSliceExt, BitVecExt};
-use alloc::vec;
-use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
 use numeric_traits::identity::Zero;
 
 pub trait BitwiseShl: BitSliceExt {
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     /// Shift a slice left by `usize` items, implemented as a series of bitwise swaps
     fn shl(left: &Self, right: usize) -> Vec<Self::Bit> {
         let bit_len = left.bit_len();
@@ -16,6 +16,36 @@ pub trait BitwiseShl: BitSliceExt {
         });
         out
     }
+
+    fn inner_shl_wrap(left: &mut Self, right: usize) -> &mut Self {
+        for idx in (0..left.bit_len()).rev() {
+            let new = idx
+                .checked_sub(right)
+                .is_some_and(|from| left.get_bit(from));
+            left.set_bit(idx, new);
+        }
+        left
+    }
+
+    /// Shift a slice left by `usize` items in place, implemented as a series of bitwise swaps,
+    /// returning None if the shift value is greater than the number of bits in the left-hand side.
+    fn shl_checked(left: &mut Self, right: usize) -> Option<&mut Self> {
+        if right > left.bit_len() {
+            return None;
+        }
+
+        Some(Self::inner_shl_wrap(left, right))
+    }
+
+    /// Shift a slice left by `usize` items in place, implemented as a series of bitwise swaps,
+    /// taking the shift value modulo the number of bits in the left-hand side.
+    fn shl_wrapping(left: &mut Self, right: usize) -> &mut Self {
+        let bit_len = left.bit_len();
+        if bit_len == 0 {
+            return left;
+        }
+        Self::inner_shl_wrap(left, right % bit_len)
+    }
 }
 
 impl<T> BitwiseShl for T where T: ?Sized + BitSliceExt {}
@@ -23,6 +53,7 @@ impl<T> BitwiseShl for T where T: ?Sized + BitSliceExt {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::algos::ElementShl;
 
     #[test]
     fn test_shl() {
@@ -35,4 +66,35 @@ mod tests {
         let slice: &[u8] = &[0b11111111];
         assert_eq!(BitwiseShl::shl(slice, 8), &[0b0, 0b11111111]);
     }
+
+    #[test]
+    fn test_shl_in_place() {
+        let cases: &[(&[u16], usize)] = &[
+            (&[0b1010101010101010, 0b1010101010101010], 1),
+            (&[0b1, 0b0], 16),
+            (&[0xFFFF, 0x1234], 7),
+            (&[0x8000, 0x0], 31),
+            (&[0xABCD, 0xEF01], 32),
+        ];
+        for &(slice, shift) in cases {
+            let mut bitwise = slice.to_vec();
+            let mut element = slice.to_vec();
+            assert_eq!(
+                BitwiseShl::shl_checked(&mut bitwise[..], shift),
+                ElementShl::shl_checked(&mut element[..], shift),
+            );
+
+            let mut bitwise = slice.to_vec();
+            let mut element = slice.to_vec();
+            assert_eq!(
+                BitwiseShl::shl_wrapping(&mut bitwise[..], shift),
+                ElementShl::shl_wrapping(&mut element[..], shift),
+            );
+        }
+
+        let mut data = [0b1u8];
+        assert_eq!(BitwiseShl::shl_checked(&mut data[..], 9), None);
+        let mut data = [0b1u8];
+        assert_eq!(BitwiseShl::shl_wrapping(&mut data[..], 9), &[0b10]);
+    }
 }
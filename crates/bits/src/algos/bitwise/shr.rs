@@ -1,11 +1,11 @@
 use crate::bit_slice::BitSliceExt;
 use crate::utils::IntSlice;
-use alloc::vec;
-use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
 use numeric_traits::identity::Zero;
 
 pub trait BitwiseShr: BitSliceExt {
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     /// Shift a slice right by `usize` items, implemented as a series of bitwise swaps
     fn shr(left: &Self, right: usize) -> Vec<Self::Bit> {
         let mut out = vec![Self::Bit::zero(); left.len()];
@@ -17,6 +17,34 @@ pub trait BitwiseShr: BitSliceExt {
         }
         IntSlice::shrink(out)
     }
+
+    fn inner_shr_wrap(left: &mut Self, right: usize) -> &mut Self {
+        for idx in 0..left.bit_len() {
+            let new = left.get_bit_opt(idx + right).unwrap_or(false);
+            left.set_bit(idx, new);
+        }
+        left
+    }
+
+    /// Shift a slice right by `usize` items in place, implemented as a series of bitwise swaps,
+    /// returning None if the shift value is greater than the number of bits in the left-hand side.
+    fn shr_checked(left: &mut Self, right: usize) -> Option<&mut Self> {
+        if right > left.bit_len() {
+            return None;
+        }
+
+        Some(Self::inner_shr_wrap(left, right))
+    }
+
+    /// Shift a slice right by `usize` items in place, implemented as a series of bitwise swaps,
+    /// taking the shift value modulo the number of bits in the left-hand side.
+    fn shr_wrapping(left: &mut Self, right: usize) -> &mut Self {
+        let bit_len = left.bit_len();
+        if bit_len == 0 {
+            return left;
+        }
+        Self::inner_shr_wrap(left, right % bit_len)
+    }
 }
 
 impl<T> BitwiseShr for T where T: ?Sized + BitSliceExt {}
@@ -24,6 +52,7 @@ impl<T> BitwiseShr for T where T: ?Sized + BitSliceExt {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::algos::ElementShr;
 
     #[test]
     fn test_shr() {
@@ -36,4 +65,35 @@ mod tests {
         let slice: &[u8] = &[0b0, 0b11111111];
         assert_eq!(BitwiseShr::shr(slice, 8), &[0b11111111]);
     }
+
+    #[test]
+    fn test_shr_in_place() {
+        let cases: &[(&[u16], usize)] = &[
+            (&[0b1010101010101010, 0b1010101010101010], 1),
+            (&[0b0, 0b1], 16),
+            (&[0xFFFF, 0x1234], 7),
+            (&[0x0, 0x8000], 31),
+            (&[0xABCD, 0xEF01], 32),
+        ];
+        for &(slice, shift) in cases {
+            let mut bitwise = slice.to_vec();
+            let mut element = slice.to_vec();
+            assert_eq!(
+                BitwiseShr::shr_checked(&mut bitwise[..], shift),
+                ElementShr::shr_checked(&mut element[..], shift),
+            );
+
+            let mut bitwise = slice.to_vec();
+            let mut element = slice.to_vec();
+            assert_eq!(
+                BitwiseShr::shr_wrapping(&mut bitwise[..], shift),
+                ElementShr::shr_wrapping(&mut element[..], shift),
+            );
+        }
+
+        let mut data = [0b10000000u8];
+        assert_eq!(BitwiseShr::shr_checked(&mut data[..], 9), None);
+        let mut data = [0b10000000u8];
+        assert_eq!(BitwiseShr::shr_wrapping(&mut data[..], 9), &[0b01000000]);
+    }
 }
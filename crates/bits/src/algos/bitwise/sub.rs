@@ -1,31 +1,26 @@
-use crate::algos::ElementNot;
-use crate::bit_slice::BitSliceExt;
-#[cfg(feature = "std")]
+use crate::algos::{ElementAdd, ElementNot};
+use crate::bit_slice::{BitSliceExt, BitSliceRead};
+#[cfg(feature = "alloc")]
 use alloc::{vec, vec::Vec};
-use numeric_traits::identity::Zero;
+use numeric_traits::identity::{One, Zero};
 
 pub trait BitwiseSub: BitSliceExt {
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     /// Subtract two slices, implemented as a bitwise sub-and-borrow
     fn sub<T>(left: &Self, right: &T) -> (Vec<Self::Bit>, bool)
     where
-        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
-        let len = usize::max(left.len(), right.len());
-        let bit_len = usize::max(left.bit_len(), right.bit_len());
+        let len = usize::max(left.len(), right.elem_len());
+        let bit_len = usize::max(left.bit_len(), right.read_bit_len());
         let mut out = vec![Self::Bit::zero(); len];
 
         let mut carry = false;
         for idx in 0..bit_len {
             let l = left.get_bit_opt(idx).unwrap_or(false);
-            let r = right.get_bit_opt(idx).unwrap_or(false);
+            let r = right.read_bit(idx).unwrap_or(false);
 
-            let c = if carry {
-                carry = false;
-                true
-            } else {
-                false
-            };
+            let c = core::mem::take(&mut carry);
 
             let new = match (l, r, c) {
                 (true, false, false) => true,
@@ -44,8 +39,9 @@ pub trait BitwiseSub: BitSliceExt {
         }
 
         if carry {
-            out.set_bit(0, !out.get_bit(0));
+            // The result is in two's complement, negate it to get the magnitude
             ElementNot::not(&mut out);
+            ElementAdd::add_wrapping(&mut out, &[Self::Bit::one()]);
         }
 
         (out, carry)
@@ -53,3 +49,15 @@ pub trait BitwiseSub: BitSliceExt {
 }
 
 impl<T> BitwiseSub for T where T: ?Sized + BitSliceExt {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sub() {
+        assert_eq!(BitwiseSub::sub(&[3u8], &[1]), (vec![2], false));
+        assert_eq!(BitwiseSub::sub(&[1u8], &[3]), (vec![2], true));
+        assert_eq!(BitwiseSub::sub(&[0u8, 1], &[0, 2]), (vec![0, 1], true));
+    }
+}
@@ -1,18 +1,18 @@
-use crate::bit_slice::BitSliceExt;
+use crate::bit_slice::{BitSliceExt, BitSliceRead};
 use crate::utils::IntSlice;
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 use alloc::{vec, vec::Vec};
 use numeric_traits::identity::{One, Zero};
 use numeric_traits::ops::overflowing::OverflowingAdd;
 
 pub trait ElementAdd: BitSliceExt {
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     /// Add two slices, implemented as element-wise add and carry
     fn add<T>(left: &Self, right: &T) -> Vec<Self::Bit>
     where
-        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
-        let len = usize::max(left.len(), right.len());
+        let len = usize::max(left.len(), right.elem_len());
         let zero = Self::Bit::zero();
         let one = Self::Bit::one();
         let mut out = vec![zero; len + 1];
@@ -21,7 +21,7 @@ pub trait ElementAdd: BitSliceExt {
 
         for idx in 0..=len {
             let l = left.get_opt(idx).unwrap_or(zero);
-            let r = right.get_opt(idx).unwrap_or(zero);
+            let r = right.read_elem(idx).unwrap_or(zero);
 
             let extra = if carry {
                 carry = false;
@@ -49,9 +49,9 @@ pub trait ElementAdd: BitSliceExt {
     /// Add two slices, implemented as wrapping element-wise add and carry with overflow check
     fn add_overflowing<'a, T>(left: &'a mut Self, right: &T) -> (&'a mut Self, bool)
     where
-        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
-        let len = usize::max(left.len(), right.len());
+        let len = usize::max(left.len(), right.elem_len());
         let zero = Self::Bit::zero();
         let one = Self::Bit::one();
 
@@ -59,7 +59,7 @@ pub trait ElementAdd: BitSliceExt {
 
         for idx in 0..len {
             let l = left.get_opt(idx).unwrap_or(zero);
-            let r = right.get_opt(idx).unwrap_or(zero);
+            let r = right.read_elem(idx).unwrap_or(zero);
 
             let extra = if carry {
                 carry = false;
@@ -84,10 +84,28 @@ pub trait ElementAdd: BitSliceExt {
         (left, carry)
     }
 
+    /// Add a slice into this one in-place, returning whether the sum carried out of the top
+    /// element. Unlike [`ElementAdd::add`], this doesn't allocate, so the carry can be fed into
+    /// further additions.
+    ///
+    /// # Panics
+    ///
+    /// If `right` is longer than `left`
+    fn add_assign_carry<T>(left: &mut Self, right: &T) -> bool
+    where
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
+    {
+        assert!(
+            right.elem_len() <= left.len(),
+            "add_assign_carry right-hand side longer than left-hand side"
+        );
+        ElementAdd::add_overflowing(left, right).1
+    }
+
     /// Add two slices, implemented as checked element-wise add and carry
     fn add_checked<'a, T>(left: &'a mut Self, right: &T) -> Option<&'a mut Self>
     where
-        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
         let (out, carry) = ElementAdd::add_overflowing(left, right);
         if carry {
@@ -100,7 +118,7 @@ pub trait ElementAdd: BitSliceExt {
     /// Add two slices, implemented as wrapping element-wise add and carry
     fn add_wrapping<'a, T>(left: &'a mut Self, right: &T) -> &'a mut Self
     where
-        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
         ElementAdd::add_overflowing(left, right).0
     }
@@ -134,4 +152,28 @@ mod tests {
     fn test_carry() {
         assert_eq!(ElementAdd::add(&[u32::MAX], &[1]), &[0, 1],);
     }
+
+    #[test]
+    fn test_add_assign_carry() {
+        let mut slice = [u8::MAX, 0];
+        assert!(!ElementAdd::add_assign_carry(&mut slice, &[1]));
+        assert_eq!(slice, [0, 1]);
+
+        let mut slice = [u8::MAX, u8::MAX];
+        assert!(ElementAdd::add_assign_carry(&mut slice, &[1]));
+        assert_eq!(slice, [0, 0]);
+
+        let mut slice = [0x80u8, 0x7E];
+        assert!(!ElementAdd::add_assign_carry(&mut slice, &[0x80, 0x80]));
+        assert_eq!(slice, [0, 0xFF]);
+        let mut slice = [0x80u8, 0x80];
+        assert!(ElementAdd::add_assign_carry(&mut slice, &[0x80, 0x80]));
+        assert_eq!(slice, [0, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_assign_carry_long() {
+        ElementAdd::add_assign_carry(&mut [0u8], &[0, 1]);
+    }
 }
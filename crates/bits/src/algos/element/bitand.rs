@@ -1,21 +1,21 @@
-use crate::bit_slice::BitSliceExt;
-#[cfg(feature = "std")]
+use crate::bit_slice::{BitSliceExt, BitSliceRead};
+#[cfg(feature = "alloc")]
 use alloc::{vec, vec::Vec};
 use numeric_traits::identity::Zero;
 
 pub trait ElementBitand: BitSliceExt {
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     fn bitand<T>(left: &Self, right: &T) -> Vec<Self::Bit>
     where
-        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
-        let len = usize::max(left.len(), right.len());
+        let len = usize::max(left.len(), right.elem_len());
         let zero = Self::Bit::zero();
         let mut out = vec![zero; len];
 
         for idx in 0..len {
             let l = left.get_opt(idx).unwrap_or(zero);
-            let r = right.get_opt(idx).unwrap_or(zero);
+            let r = right.read_elem(idx).unwrap_or(zero);
 
             out.set(idx, l & r);
         }
@@ -1,30 +1,24 @@
-use crate::bit_slice::BitSliceExt;
+use crate::bit_slice::{BitSliceExt, BitSliceRead};
 use core::cmp::Ordering;
 use numeric_traits::identity::Zero;
 
 pub trait ElementCmp: BitSliceExt {
     fn cmp<T>(left: &Self, right: &T) -> Ordering
     where
-        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
         let zero = Self::Bit::zero();
-        let len = usize::max(left.len(), right.len());
-        // let iter = Iterator::zip(left.iter(), right.iter());
-        for idx in 0..len {
+        let len = usize::max(left.len(), right.elem_len());
+        // Compare from the most significant element down
+        for idx in (0..len).rev() {
             match Ord::cmp(
                 &left.get_opt(idx).unwrap_or(zero),
-                &right.get_opt(idx).unwrap_or(zero),
+                &right.read_elem(idx).unwrap_or(zero),
             ) {
                 Ordering::Equal => (),
                 ord => return ord,
             }
         }
-        // for (l, r) in iter {
-        //     match Ord::cmp(l, r) {
-        //         Ordering::Equal => (),
-        //         ord => return ord,
-        //     }
-        // }
         Ordering::Equal
     }
 }
@@ -58,5 +52,9 @@ mod tests {
         assert_eq!(ElementCmp::cmp(&[1u32], &[2]), Ordering::Less,);
 
         assert_eq!(ElementCmp::cmp(&[0u32, 2], &[0, 1]), Ordering::Greater,);
+
+        assert_eq!(ElementCmp::cmp(&[0xFFu8, 0], &[0, 1]), Ordering::Less,);
+
+        assert_eq!(ElementCmp::cmp(&[0u8, 1], &[0xFF, 0]), Ordering::Greater,);
     }
 }
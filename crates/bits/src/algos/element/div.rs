@@ -1,97 +1,196 @@
-use crate::algos::{ElementAdd, ElementCmp, ElementShl, ElementShr, ElementSub};
 use crate::bit_slice::{BitLike, BitSliceExt};
 use crate::utils::IntSlice;
-#[cfg(feature = "std")]
-use alloc::{vec, vec::Vec};
-use numeric_traits::identity::{One, Zero};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use numeric_traits::identity::Zero;
+
+/// Divide the two-element value `[lo, hi]` by `div`, returning the quotient and remainder. `hi`
+/// must be less than `div`, so the quotient fits in a single element.
+fn div_wide<B: BitLike>(hi: B, lo: B, div: B) -> (B, B) {
+    let mut quot = B::zero();
+    let mut rem = hi;
+    for bit in (0..B::BIT_LEN).rev() {
+        let carry = rem >> (B::BIT_LEN - 1) != B::zero();
+        rem = (rem << 1) | ((lo >> bit) & B::one());
+        if carry || rem >= div {
+            rem = rem.overflowing_sub(div).0;
+            quot |= B::one() << bit;
+        }
+    }
+    (quot, rem)
+}
+
+/// Get element `idx` of `slice` shifted left by `shift` bits, as if the whole slice had been
+/// shifted. Indices outside the slice read as zero.
+fn shifted<B: BitLike, S: ?Sized + BitSliceExt<Bit = B>>(slice: &S, idx: usize, shift: usize) -> B {
+    let get = |idx: usize| slice.get_opt(idx).unwrap_or(B::zero());
+    if shift == 0 {
+        get(idx)
+    } else {
+        let low = idx
+            .checked_sub(1)
+            .map_or(B::zero(), |idx| get(idx) >> (B::BIT_LEN - shift));
+        (get(idx) << shift) | low
+    }
+}
+
+/// Knuth's Algorithm D, performed in place. Each step replaces the top element of the current
+/// remainder window with the quotient digit it produced, except the first step, whose digit is
+/// returned. On completion, `num[..n]` holds the remainder and `num[n..]` the low digits of the
+/// quotient, where `n` is the length of the divisor without leading zeros.
+///
+/// Instead of normalizing the inputs, the quotient digit estimates are made from shifted views of
+/// the top elements. The quotient is the same either way, and this avoids needing any scratch
+/// space or a final un-normalizing shift of the remainder.
+///
+/// Returns `None` if the divisor is zero, or the divisor is longer than `num`, in which case the
+/// quotient is zero and `num` is the remainder.
+fn div_in_place<B, T>(num: &mut [B], div: &T) -> Option<(usize, B)>
+where
+    B: BitLike,
+    T: ?Sized + BitSliceExt<Bit = B>,
+{
+    let n = div.slice().iter().rposition(|&e| e != B::zero())? + 1;
+    let len = num.len();
+    if n > len {
+        return None;
+    }
+    let div = &div.slice()[..n];
+
+    let top = div[n - 1];
+    let mut shift = 0;
+    while (top << shift) >> (B::BIT_LEN - 1) == B::zero() {
+        shift += 1;
+    }
+    let div_hi = shifted(div, n - 1, shift);
+    let div_lo = if n > 1 {
+        shifted(div, n - 2, shift)
+    } else {
+        B::zero()
+    };
+
+    let mut high_digit = B::zero();
+    for j in (0..=len - n).rev() {
+        // Estimate the quotient digit from the top of the window. For a single-element divisor the
+        // estimate is exact, otherwise it's at most one too large.
+        let num_hi = shifted(&*num, j + n, shift);
+        let num_mid = shifted(&*num, j + n - 1, shift);
+        let mut quot = if n == 1 {
+            div_wide(num_hi, num_mid, div_hi).0
+        } else {
+            let (mut quot, rem) = if num_hi >= div_hi {
+                let (rem, overflow) = num_mid.overflowing_add(div_hi);
+                (B::max_value(), (!overflow).then_some(rem))
+            } else {
+                let (quot, rem) = div_wide(num_hi, num_mid, div_hi);
+                (quot, Some(rem))
+            };
+            let num_lo = shifted(&*num, j + n - 2, shift);
+            let mut rem = rem;
+            while let Some(r) = rem {
+                let (lo, hi) = quot.widening_mul(div_lo, B::zero());
+                if hi < r || (hi == r && lo <= num_lo) {
+                    break;
+                }
+                quot -= B::one();
+                let (r, overflow) = r.overflowing_add(div_hi);
+                rem = (!overflow).then_some(r);
+            }
+            quot
+        };
+
+        // Subtract `quot * div` from the window
+        let mut carry = B::zero();
+        let mut borrow = false;
+        for (i, &d) in div.iter().enumerate() {
+            let (lo, hi) = quot.widening_mul(d, carry);
+            let (val, b1) = num[j + i].overflowing_sub(lo);
+            let (val, b2) = val.overflowing_sub(if borrow { B::one() } else { B::zero() });
+            num[j + i] = val;
+            carry = hi;
+            borrow = b1 || b2;
+        }
+        let win_top = num.get(j + n).copied().unwrap_or(B::zero());
+        let (val, b1) = win_top.overflowing_sub(carry);
+        let (_, b2) = val.overflowing_sub(if borrow { B::one() } else { B::zero() });
+
+        // The estimate was one too large, add the divisor back once
+        if b1 || b2 {
+            quot -= B::one();
+            let mut carry = false;
+            for (i, &d) in div.iter().enumerate() {
+                let (val, c1) = num[j + i].overflowing_add(d);
+                let (val, c2) = val.overflowing_add(if carry { B::one() } else { B::zero() });
+                num[j + i] = val;
+                carry = c1 || c2;
+            }
+        }
+
+        // The window is now less than the divisor, so its top element is free
+        match num.get_mut(j + n) {
+            Some(e) => *e = quot,
+            None => high_digit = quot,
+        }
+    }
+
+    Some((n, high_digit))
+}
 
 pub trait ElementDiv: BitSliceExt {
-    #[cfg(feature = "std")]
-    /// Divide two slices, implemented as long division.
+    #[cfg(feature = "alloc")]
+    /// Divide two slices, implemented as long division one element at a time. Returns the
+    /// quotient and remainder.
     ///
-    /// <div class="warning">
-    /// This method is, unlike most element methods, *much slower* than the equivalent Bitwise
-    /// method. Prefer `BitwiseDiv::div_long` for non-academic use-cases.
-    /// </div>
+    /// # Panics
+    ///
+    /// If `right` is zero
     fn div_long<T>(left: &Self, right: &T) -> (Vec<Self::Bit>, Vec<Self::Bit>)
     where
         T: ?Sized + BitSliceExt<Bit = Self::Bit>,
     {
-        let len = usize::max(left.len(), right.len());
-
-        let mut quotient = vec![Self::Bit::zero(); len];
-        let mut remainder = vec![Self::Bit::zero(); len];
-        let mut one = vec![Self::Bit::zero(); len];
-        one[len - 1] = Self::Bit::one();
-
-        for idx in (0..len).rev() {
-            // Shift left by 1 element
-            ElementShl::shl_wrapping(&mut remainder, Self::Bit::BIT_LEN);
-            // Set new element to left[idx]
-            remainder.set(0, left.get(idx));
-
-            // Mathy stuff:
-            //   The remainder will *always* be no more than one digit greater than the divisor
-            //   Which means the divisor will go into the remainder at most Self::Bit::MAX times
-            //
-            //   (99 / 100, remainder of 99, is the worst case)
-
-            // TODO: This is the slow bit. Maybe do some mul/sub stuff instead?
-            //       Can this not be a loop, maybe nested division or something?
-            // While remainder is greater than divisor
-            while ElementCmp::cmp(&remainder, right).is_ge() {
-                // Subtract remainder by divisor
-                ElementSub::sub_wrapping(&mut remainder, right);
-                // Add 1 to quotient at idx
-                ElementAdd::add_wrapping(&mut quotient, &one);
-            }
+        assert!(
+            right.slice().iter().any(|&e| e != Self::Bit::zero()),
+            "attempt to divide by zero"
+        );
 
-            ElementShr::shr_wrapping(&mut one, Self::Bit::BIT_LEN);
+        let mut num = left.slice().to_vec();
+        match div_in_place(&mut num, right) {
+            Some((n, high_digit)) => {
+                let mut quotient = num.split_off(n);
+                quotient.push(high_digit);
+                (IntSlice::shrink(quotient), IntSlice::shrink(num))
+            }
+            None => (Vec::from([Self::Bit::zero()]), IntSlice::shrink(num)),
         }
-
-        (IntSlice::shrink(quotient), IntSlice::shrink(remainder))
     }
 
-    /// Divide two slices, implemented as long division with overflow check
-    fn div_long_overflowing<'a, T>(_left: &'a mut Self, _right: &T) -> (&'a mut Self, bool)
+    /// Divide two slices, implemented as long division one element at a time. The overflow flag
+    /// is set if `right` is zero, in which case `left` is left unchanged.
+    fn div_long_overflowing<'a, T>(left: &'a mut Self, right: &T) -> (&'a mut Self, bool)
     where
-        T: ?Sized + BitSliceExt,
+        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
     {
-        todo!()
-    }
-
-    /*
-       // Make sure left * right <= bits
-       let k = usize::max(left.bit_len(), right.bit_len());
-       println!("k: {k}");
-       let pow = 2usize.pow((k+1) as u32);
-       let bits = BitSlice::new(int_to_arr(pow));
-       println!("bits: {bits:?}");
-
-       // Get an initial guess. For now this can be whatever
-       // This should be able to fit at least `bits`
-       let mut x = left.clone();
-       println!("x: {x:?}");
-
-       for _ in 0..k {
-           // x+1 = x * (2^(k+1) - x * B) >> k
-           let b = right.as_slice();
-           println!("b: {b:?}");
-           let bx = BitSlice::mul_long_element_wrapping(x.clone(), b);
-           println!("bx: {bx:?}");
-           let sub = BitSlice::sub_element_wrapping(bits.clone(), bx);
-           println!("sub: {sub:?}");
-           x = BitSlice::shr_wrap_and_mask_wrapping(BitSlice::mul_long_element_wrapping(x, sub), k);
-           println!("x: {x:?}");
-       }
-
-       (BitSlice::shr_wrap_and_mask_wrapping(BitSlice::mul_long_element_wrapping(left, x), k), false)
-    */
+        if right.slice().iter().all(|&e| e == Self::Bit::zero()) {
+            return (left, true);
+        }
+
+        let num = left.slice_mut();
+        match div_in_place(num, right) {
+            Some((n, high_digit)) => {
+                let len = num.len();
+                num.copy_within(n.., 0);
+                num[len - n] = high_digit;
+                num[len - n + 1..].fill(Self::Bit::zero());
+            }
+            None => num.fill(Self::Bit::zero()),
+        }
+        (left, false)
+    }
 
     /// divide two slices, implemented as checked long division
     fn div_long_checked<'a, T>(left: &'a mut Self, right: &T) -> Option<&'a mut Self>
     where
-        T: ?Sized + BitSliceExt,
+        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
     {
         let (out, carry) = ElementDiv::div_long_overflowing(left, right);
         if carry {
@@ -104,10 +203,48 @@ pub trait ElementDiv: BitSliceExt {
     /// Divide two slices, implemented as wrapping long division
     fn div_long_wrapping<'a, T>(left: &'a mut Self, right: &T) -> &'a mut Self
     where
-        T: ?Sized + BitSliceExt,
+        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
     {
         ElementDiv::div_long_overflowing(left, right).0
     }
+
+    /// Find the remainder of dividing two slices, implemented as long division one element at a
+    /// time. The overflow flag is set if `right` is zero, in which case `left` is left unchanged.
+    fn rem_long_overflowing<'a, T>(left: &'a mut Self, right: &T) -> (&'a mut Self, bool)
+    where
+        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+    {
+        if right.slice().iter().all(|&e| e == Self::Bit::zero()) {
+            return (left, true);
+        }
+
+        let num = left.slice_mut();
+        if let Some((n, _)) = div_in_place(num, right) {
+            num[n..].fill(Self::Bit::zero());
+        }
+        (left, false)
+    }
+
+    /// Find the remainder of dividing two slices, implemented as checked long division
+    fn rem_long_checked<'a, T>(left: &'a mut Self, right: &T) -> Option<&'a mut Self>
+    where
+        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+    {
+        let (out, carry) = ElementDiv::rem_long_overflowing(left, right);
+        if carry {
+            None
+        } else {
+            Some(out)
+        }
+    }
+
+    /// Find the remainder of dividing two slices, implemented as wrapping long division
+    fn rem_long_wrapping<'a, T>(left: &'a mut Self, right: &T) -> &'a mut Self
+    where
+        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+    {
+        ElementDiv::rem_long_overflowing(left, right).0
+    }
 }
 
 impl<T> ElementDiv for T where T: ?Sized + BitSliceExt {}
@@ -115,38 +252,105 @@ impl<T> ElementDiv for T where T: ?Sized + BitSliceExt {}
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::algos::BitwiseDiv;
+    use alloc::vec;
+
+    /// Simple xorshift generator, so tests are reproducible without extra dependencies
+    struct XorShift(u64);
+
+    impl XorShift {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        fn slice<const N: usize>(&mut self, len: usize) -> [u8; N] {
+            let mut out = [0; N];
+            for e in &mut out[..len] {
+                *e = self.next() as u8;
+            }
+            out
+        }
+    }
 
     #[test]
     fn test_rem() {
         assert_eq!(ElementDiv::div_long(&[10u32], &[2]).1, &[0],);
         assert_eq!(ElementDiv::div_long(&[17u32], &[11]).1, &[6],);
-        // TODO: Unworkably slow
-        // assert_eq!(ElementDiv::div_long(&[0u32, 1], &[7]).1, &[2],);
-    }
-
-    // #[test]
-    // fn test_div() {
-    //     let slice1: &[u8] = &[0b10];
-    //     let slice2: &[u8] = &[0b01];
-    //
-    //     assert_eq!(ElementDiv::div_long(slice1, slice2).0, &[0b10]);
-    //
-    //     let slice3: &[u8] = &[0b10];
-    //     let slice4: &[u8] = &[0b10];
-    //
-    //     assert_eq!(ElementDiv::div_long(slice3, slice4).0, &[0b01]);
-    //
-    //     let slice5: &[u8] = &[0b00000000, 0b1];
-    //     let slice6: &[u8] = &[0b00000010];
-    //
-    //     assert_eq!(ElementDiv::div_long(slice5, slice6).0, &[0b10000000]);
-    //
-    //     let slice7: &[u8] = &[0b0, 0b0, 0b0, 0b1];
-    //     let slice8: &[u8] = &[0b10];
-    //
-    //     assert_eq!(
-    //         ElementDiv::div_long(slice7, slice8).0,
-    //         &[0b0, 0b0, 0b10000000]
-    //     );
-    // }
+        assert_eq!(ElementDiv::div_long(&[0u32, 1], &[7]).1, &[4],);
+    }
+
+    #[test]
+    fn test_div() {
+        assert_eq!(ElementDiv::div_long(&[0b10u8], &[0b01]).0, &[0b10]);
+        assert_eq!(ElementDiv::div_long(&[0b10u8], &[0b10]).0, &[0b01]);
+        assert_eq!(ElementDiv::div_long(&[0u8, 0b1], &[0b10]).0, &[0b10000000]);
+        assert_eq!(
+            ElementDiv::div_long(&[0u8, 0, 0, 0b1], &[0b10]).0,
+            &[0, 0, 0b10000000]
+        );
+        assert_eq!(ElementDiv::div_long(&[5u8], &[0, 1]), (vec![0], vec![5]));
+        assert_eq!(
+            ElementDiv::div_long(&[usize::MAX; 4], &[usize::MAX; 2]),
+            (vec![1, 0, 1], vec![0])
+        );
+    }
+
+    #[test]
+    fn test_in_place() {
+        let mut left = [0u8, 0, 1];
+        assert_eq!(
+            ElementDiv::div_long_checked(&mut left, &[3, 1]),
+            Some(&mut [0xFD, 0, 0])
+        );
+        let mut left = [0u8, 0, 1];
+        assert_eq!(
+            ElementDiv::rem_long_checked(&mut left, &[3, 1]),
+            Some(&mut [0x09, 0, 0])
+        );
+
+        let mut left = [7u8, 1];
+        assert_eq!(ElementDiv::div_long_checked(&mut left, &[0]), None);
+        assert_eq!(ElementDiv::rem_long_checked(&mut left, &[0, 0]), None);
+        assert_eq!(left, [7, 1]);
+    }
+
+    #[test]
+    fn test_matches_bitwise() {
+        let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+        for _ in 0..2000 {
+            let left_len = (rng.next() % 8) as usize + 1;
+            let right_len = (rng.next() % 8) as usize + 1;
+            let left = rng.slice::<8>(left_len);
+            let mut right = rng.slice::<8>(right_len);
+            if right.iter().all(|&e| e == 0) {
+                right[0] = 1;
+            }
+
+            let (bit_quot, bit_rem) = BitwiseDiv::div_long(&left, &right);
+            let (elem_quot, elem_rem) = ElementDiv::div_long(&left, &right);
+            assert_eq!(
+                IntSlice::shrink(bit_quot),
+                elem_quot,
+                "{left:?} / {right:?}"
+            );
+            assert_eq!(IntSlice::shrink(bit_rem), elem_rem, "{left:?} % {right:?}");
+
+            let mut scratch = [0; 8];
+            let mut bit = left;
+            BitwiseDiv::div_long_wrapping(&mut bit, &right, &mut scratch);
+            let mut elem = left;
+            ElementDiv::div_long_wrapping(&mut elem, &right);
+            assert_eq!(bit, elem, "{left:?} / {right:?}");
+
+            let mut scratch = [0; 8];
+            let mut bit = left;
+            BitwiseDiv::rem_long_wrapping(&mut bit, &right, &mut scratch);
+            let mut elem = left;
+            ElementDiv::rem_long_wrapping(&mut elem, &right);
+            assert_eq!(bit, elem, "{left:?} % {right:?}");
+        }
+    }
 }
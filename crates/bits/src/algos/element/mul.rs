@@ -1,90 +1,113 @@
-use crate::bit_slice::BitSliceExt;
+use crate::bit_slice::{BitSliceExt, BitSliceRead};
 use crate::utils::IntSlice;
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 use alloc::{vec, vec::Vec};
+use core::mem;
 use numeric_traits::identity::{One, Zero};
 use numeric_traits::ops::overflowing::OverflowingAdd;
 use numeric_traits::ops::widening::WideningMul;
 
 pub trait ElementMul: BitSliceExt {
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     /// Multiply two slices, implemented as shift-and-add
     fn mul<T>(left: &Self, right: &T) -> Vec<Self::Bit>
     where
-        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
         let zero = Self::Bit::zero();
-        let mut out = vec![zero; left.len() + right.len()];
+        let mut out = vec![zero; left.len() + right.elem_len()];
 
         left.slice().iter().enumerate().for_each(|(idx, &l)| {
             let mut carry = zero;
 
-            for (offset, &r) in right.slice().iter().enumerate() {
+            for offset in 0..right.elem_len() {
+                let r = right.read_elem(offset).unwrap_or(zero);
                 let (low, high) = Self::Bit::widening_mul(l, r, carry);
                 carry = high;
                 out.add_item(idx + offset, low);
             }
 
             if carry != zero {
-                out.add_item(idx + right.slice().len(), carry);
+                out.add_item(idx + right.elem_len(), carry);
             }
         });
 
         IntSlice::shrink(out)
     }
 
+    #[cfg(feature = "alloc")]
+    /// Multiply a slice by a single element, carrying between elements with a widening multiply
+    fn mul_scalar(left: &Self, right: Self::Bit) -> Vec<Self::Bit> {
+        let mut out = left.slice().to_vec();
+        let carry = ElementMul::mul_scalar_assign(&mut out, right);
+        out.push(carry);
+        IntSlice::shrink(out)
+    }
+
+    /// Multiply a slice by a single element in-place, returning the final carry element that
+    /// didn't fit in the slice
+    fn mul_scalar_assign(left: &mut Self, right: Self::Bit) -> Self::Bit {
+        left.slice_mut()
+            .iter_mut()
+            .fold(Self::Bit::zero(), |carry, l| {
+                let (low, high) = Self::Bit::widening_mul(*l, right, carry);
+                *l = low;
+                high
+            })
+    }
+
+    /// Add a single item to this slice at a given index, carrying into higher elements as needed.
+    /// Returns whether any part of the value was carried past the end of the slice.
     fn add_item(&mut self, mut idx: usize, mut val: Self::Bit) -> bool {
-        let slice = self.slice_mut();
-        let mut carry = false;
+        if val == Self::Bit::zero() {
+            return false;
+        }
 
+        let slice = self.slice_mut();
         while let Some(loc) = slice.get_mut(idx) {
-            let (new, new_carry) = loc.overflowing_add(val);
-            carry = new_carry;
+            let (new, carry) = loc.overflowing_add(val);
             *loc = new;
-            idx += 1;
 
             if !carry {
-                break;
-            } else {
-                val = Self::Bit::one();
+                return false;
             }
+            idx += 1;
+            val = Self::Bit::one();
         }
 
-        carry
+        true
     }
 
     /// Multiply two slices, implemented as shift-and-add with overflow check
     fn mul_overflowing<'a, T>(left: &'a mut Self, right: &T) -> (&'a mut Self, bool)
     where
-        T: BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
         let zero = Self::Bit::zero();
 
-        let overflow = right
-            .slice()
-            .iter()
-            .enumerate()
-            .rev()
-            .fold(false, |overflow, (idx, &l)| {
-                // From the top to bottom, add N shifted copies of M. This can be done by taking each
-                // element of the left and doing a widening mul, carrying the upper, and repeating
-                let mut new_overflow = false;
-                let mut carry = zero;
-
-                for (offset, &r) in right.slice().iter().enumerate() {
-                    let (low, high) = Self::Bit::widening_mul(l, r, carry);
-                    carry = high;
-                    if left.add_item(idx + offset, low) {
-                        new_overflow = true;
-                    }
-                }
+        let overflow = (0..left.len()).rev().fold(false, |overflow, idx| {
+            // From the top to bottom, add N shifted copies of M. This can be done by taking each
+            // element of the left and doing a widening mul, carrying the upper, and repeating.
+            // Lower elements are untouched until their turn, so this can be done in-place.
+            let l = mem::replace(&mut left.slice_mut()[idx], zero);
+            let mut new_overflow = false;
+            let mut carry = zero;
 
-                if carry != zero && left.add_item(idx + right.slice().len(), carry) {
+            for offset in 0..right.elem_len() {
+                let r = right.read_elem(offset).unwrap_or(zero);
+                let (low, high) = Self::Bit::widening_mul(l, r, carry);
+                carry = high;
+                if left.add_item(idx + offset, low) {
                     new_overflow = true;
                 }
+            }
+
+            if carry != zero && left.add_item(idx + right.elem_len(), carry) {
+                new_overflow = true;
+            }
 
-                new_overflow || overflow
-            });
+            new_overflow || overflow
+        });
 
         (left, overflow)
     }
@@ -92,7 +115,7 @@ pub trait ElementMul: BitSliceExt {
     /// Multiply two slices, implemented as checked shift-and-add
     fn mul_checked<'a, T>(left: &'a mut Self, right: &T) -> Option<&'a mut Self>
     where
-        T: BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
         let (out, carry) = ElementMul::mul_overflowing(left, right);
         if carry {
@@ -105,7 +128,7 @@ pub trait ElementMul: BitSliceExt {
     /// Multiply two slices, implemented as wrapping shift-and-add
     fn mul_wrapping<'a, T>(left: &'a mut Self, right: &T) -> &'a mut Self
     where
-        T: BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
         ElementMul::mul_overflowing(left, right).0
     }
@@ -139,4 +162,56 @@ mod tests {
 
         assert_eq!(ElementMul::mul(slice7, slice8), &[0b100]);
     }
+
+    #[test]
+    fn test_mul_scalar() {
+        assert_eq!(
+            ElementMul::mul_scalar(&[u8::MAX, u8::MAX], 3),
+            &[0xFD, 0xFF, 0x02]
+        );
+        assert_eq!(
+            ElementMul::mul_scalar(&[u8::MAX, u8::MAX], 1),
+            &[0xFF, 0xFF]
+        );
+        assert_eq!(ElementMul::mul_scalar(&[u8::MAX, u8::MAX], 0), &[0]);
+        assert_eq!(ElementMul::mul_scalar(&[0x80u8, 0x80], 2), &[0, 1, 1]);
+
+        let mut slice = [u8::MAX, u8::MAX];
+        assert_eq!(ElementMul::mul_scalar_assign(&mut slice, 3), 0x02);
+        assert_eq!(slice, [0xFD, 0xFF]);
+
+        let mut slice = [0x10u8, 0x01];
+        assert_eq!(ElementMul::mul_scalar_assign(&mut slice, 0x10), 0);
+        assert_eq!(slice, [0x00, 0x11]);
+    }
+
+    #[test]
+    fn test_mul_overflow() {
+        let mut slice1: [u8; 2] = [0, 1];
+        assert!(ElementMul::mul_checked(&mut slice1, &[0, 1]).is_none());
+
+        let mut slice2: [u8; 2] = [0x10, 0];
+        assert!(ElementMul::mul_checked(&mut slice2, &[0, 0x10]).is_none());
+
+        let mut slice3: [u8; 2] = [0x10, 0];
+        assert_eq!(
+            ElementMul::mul_checked(&mut slice3, &[0, 0x0F]),
+            Some(&mut [0, 0xF0])
+        );
+    }
+
+    #[test]
+    fn test_mul_wrapping() {
+        let mut slice1: [u8; 2] = [3, 0];
+        ElementMul::mul_wrapping(&mut slice1, &[5, 0]);
+        assert_eq!(slice1, [15, 0]);
+
+        let mut slice2: [u8; 2] = [0, 1];
+        ElementMul::mul_wrapping(&mut slice2, &[3, 0]);
+        assert_eq!(slice2, [0, 3]);
+
+        let mut slice3: [u8; 2] = [0xFF, 0];
+        ElementMul::mul_wrapping(&mut slice3, &[0xFF, 0]);
+        assert_eq!(slice3, [0x01, 0xFE]);
+    }
 }
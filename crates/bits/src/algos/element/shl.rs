@@ -1,6 +1,5 @@
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 use alloc::{vec, vec::Vec};
-use numeric_traits::cast::FromTruncating;
 use numeric_traits::class::Bounded;
 use numeric_traits::identity::Zero;
 
@@ -8,7 +7,7 @@ use crate::bit_slice::{BitLike, BitSliceExt};
 use crate::utils::IntSlice;
 
 pub trait ElementShl: BitSliceExt {
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     /// Shift a slice left by `usize` items, implemented as a series of shifts and masks
     fn shl(left: &Self, right: usize) -> Vec<Self::Bit> {
         let arr_shift = (right / Self::Bit::BIT_LEN) + 1;
@@ -78,12 +77,14 @@ pub trait ElementShl: BitSliceExt {
         Some(Self::inner_shl_wrap_and_mask(left, right))
     }
 
-    /// Shift a slice left by `usize` items, implemented as a series of shifts and masks, masking
-    /// the shift value if it is greater than the number of bits in the left-hand side.
+    /// Shift a slice left by `usize` items, implemented as a series of shifts and masks, taking
+    /// the shift value modulo the number of bits in the left-hand side.
     fn shl_wrapping(left: &mut Self, right: usize) -> &mut Self {
         let bit_len = left.bit_len();
-        let num_zeroes = usize::truncate_from(bit_len.leading_zeros()) + 1;
-        Self::inner_shl_wrap_and_mask(left, right & usize::MAX >> num_zeroes)
+        if bit_len == 0 {
+            return left;
+        }
+        Self::inner_shl_wrap_and_mask(left, right % bit_len)
     }
 }
 
@@ -1,12 +1,12 @@
 use crate::bit_slice::{BitLike, BitSliceExt};
 use crate::utils::IntSlice;
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 use alloc::{vec, vec::Vec};
 use numeric_traits::class::Bounded;
 use numeric_traits::identity::Zero;
 
 pub trait ElementShr: BitSliceExt {
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     /// Shift a slice left by `usize` items, implemented as a series of shifts and masks
     fn shr(left: &Self, right: usize) -> Vec<Self::Bit> {
         let arr_shift = (right / Self::Bit::BIT_LEN) + 1;
@@ -20,17 +20,17 @@ pub trait ElementShr: BitSliceExt {
             let high = val << inverse_elem_shift;
             let low = val >> elem_shift;
 
-            if idx != 0 {
-                let high = (out.get_opt(idx - arr_shift).unwrap_or(zero) & !elem_mask)
-                    | (high & elem_mask);
+            if let Some(idx) = usize::checked_sub(idx, arr_shift) {
+                let high = (out.get_opt(idx).unwrap_or(zero) & !elem_mask) | (high & elem_mask);
 
-                out.set_ignore(idx - arr_shift, high);
+                out.set_ignore(idx, high);
             }
 
-            let low =
-                (out.get_opt(idx + 1 - arr_shift).unwrap_or(zero) & elem_mask) | (low & !elem_mask);
+            if let Some(idx) = usize::checked_sub(idx + 1, arr_shift) {
+                let low = (out.get_opt(idx).unwrap_or(zero) & elem_mask) | (low & !elem_mask);
 
-            out.set_ignore(idx + 1 - arr_shift, low);
+                out.set_ignore(idx, low);
+            }
         });
 
         IntSlice::shrink(out)
@@ -57,13 +57,13 @@ pub trait ElementShr: BitSliceExt {
                 left.set_ignore(idx, high);
             }
 
+            // The high bits of the destination are stale until the next element is shifted into
+            // them, or should be empty if this is the last element, so they aren't kept.
             if let Some(idx) = usize::checked_sub(idx + 1, arr_shift) {
-                let low = (left.get_opt(idx).unwrap_or(zero) & elem_mask) | (low & !elem_mask);
-
-                left.set_ignore(idx, low);
+                left.set_ignore(idx, low & !elem_mask);
             }
         });
-        let empty = left.len() - arr_shift + 1;
+        let empty = (left.len() + 1).saturating_sub(arr_shift);
         left.slice_mut()[empty..].fill(zero);
 
         left
@@ -79,13 +79,36 @@ pub trait ElementShr: BitSliceExt {
         Some(Self::inner_shr_wrap_and_mask(left, right))
     }
 
-    /// Shift a slice left by `usize` items, implemented as a series of shifts and masks, masking
-    /// the shift value if it is greater than the number of bits in the left-hand side.
+    /// Shift a slice right by `usize` items, implemented as a series of shifts and masks, taking
+    /// the shift value modulo the number of bits in the left-hand side.
     fn shr_wrapping(left: &mut Self, right: usize) -> &mut Self {
         let bit_len = left.bit_len();
-        let num_zeroes = (bit_len.leading_zeros() as usize) + 1;
-        Self::inner_shr_wrap_and_mask(left, right & usize::MAX >> num_zeroes)
+        if bit_len == 0 {
+            return left;
+        }
+        Self::inner_shr_wrap_and_mask(left, right % bit_len)
     }
 }
 
 impl<T> ElementShr for T where T: ?Sized + BitSliceExt {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrapping_overflow() {
+        let mut data = [0b1010101010101010u16, 0b1010101010101010];
+        assert_eq!(
+            ElementShr::shr_wrapping(&mut data, 1),
+            &[0b0101010101010101, 0b0101010101010101],
+        );
+        let mut data = [0b0u8, 0b10000001];
+        assert_eq!(
+            ElementShr::shr_wrapping(&mut data, 1),
+            &[0b10000000, 0b01000000]
+        );
+        let mut data = [0b0u8, 0b1];
+        assert_eq!(ElementShr::shr_wrapping(&mut data, 8), &[0b1, 0b0]);
+    }
+}
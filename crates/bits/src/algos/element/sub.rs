@@ -1,19 +1,19 @@
-use crate::algos::element::ElementNot;
-use crate::bit_slice::BitSliceExt;
+use crate::algos::element::{ElementAdd, ElementNot};
+use crate::bit_slice::{BitSliceExt, BitSliceRead};
 use crate::utils::IntSlice;
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 use alloc::{vec, vec::Vec};
 use numeric_traits::identity::{One, Zero};
 use numeric_traits::ops::overflowing::OverflowingSub;
 
 pub trait ElementSub: BitSliceExt {
-    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
     /// Subtract two slices, implemented as element-wise subtract and borrow
     fn sub<T>(left: &Self, right: &T) -> (Vec<Self::Bit>, bool)
     where
-        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
-        let len = usize::max(left.len(), right.len());
+        let len = usize::max(left.len(), right.elem_len());
         let zero = Self::Bit::zero();
         let one = Self::Bit::one();
         let mut out = vec![zero; len];
@@ -21,7 +21,7 @@ pub trait ElementSub: BitSliceExt {
         let mut carry = false;
         for idx in 0..len {
             let l = left.get_opt(idx).unwrap_or(zero);
-            let r = right.get_opt(idx).unwrap_or(zero);
+            let r = right.read_elem(idx).unwrap_or(zero);
 
             let extra = if carry {
                 carry = false;
@@ -44,8 +44,9 @@ pub trait ElementSub: BitSliceExt {
         }
 
         if carry {
-            out.set_bit(0, !out.get_bit(0));
+            // The result is in two's complement, negate it to get the magnitude
             ElementNot::not(&mut out);
+            ElementAdd::add_wrapping(&mut out, &[Self::Bit::one()]);
         }
 
         (IntSlice::shrink(out), carry)
@@ -55,9 +56,9 @@ pub trait ElementSub: BitSliceExt {
     /// check
     fn sub_overflowing<'a, T>(left: &'a mut Self, right: &T) -> (&'a mut Self, bool)
     where
-        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
-        let len = usize::max(left.len(), right.len());
+        let len = usize::max(left.len(), right.elem_len());
         let zero = Self::Bit::zero();
         let one = Self::Bit::one();
 
@@ -65,7 +66,7 @@ pub trait ElementSub: BitSliceExt {
 
         for idx in 0..len {
             let l = left.get_opt(idx).unwrap_or(zero);
-            let r = right.get_opt(idx).unwrap_or(zero);
+            let r = right.read_elem(idx).unwrap_or(zero);
 
             let extra = if carry {
                 carry = false;
@@ -90,10 +91,28 @@ pub trait ElementSub: BitSliceExt {
         (left, carry)
     }
 
+    /// Subtract a slice from this one in-place, returning whether the difference borrowed out of the
+    /// top element. Unlike [`ElementSub::sub`], this doesn't allocate, so the borrow can be fed
+    /// into further subtractions.
+    ///
+    /// # Panics
+    ///
+    /// If `right` is longer than `left`
+    fn sub_assign_borrow<T>(left: &mut Self, right: &T) -> bool
+    where
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
+    {
+        assert!(
+            right.elem_len() <= left.len(),
+            "sub_assign_borrow right-hand side longer than left-hand side"
+        );
+        ElementSub::sub_overflowing(left, right).1
+    }
+
     /// Subtract two slices, implemented as checked element-wise subtract and borrow
     fn sub_checked<'a, T>(left: &'a mut Self, right: &T) -> Option<&'a mut Self>
     where
-        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
         let (out, carry) = ElementSub::sub_overflowing(left, right);
         if carry {
@@ -106,7 +125,7 @@ pub trait ElementSub: BitSliceExt {
     /// Subtract two slices, implemented as wrapping element-wise subtract and borrow
     fn sub_wrapping<'a, T>(left: &'a mut Self, right: &T) -> &'a mut Self
     where
-        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+        T: ?Sized + BitSliceRead<Bit = Self::Bit>,
     {
         ElementSub::sub_overflowing(left, right).0
     }
@@ -126,6 +145,10 @@ mod tests {
 
         assert_eq!(ElementSub::sub(&[0u32], &[1]), (vec![1], true),);
 
+        assert_eq!(ElementSub::sub(&[1u32], &[3]), (vec![2], true),);
+
+        assert_eq!(ElementSub::sub(&[3u32], &[5]), (vec![2], true),);
+
         assert_eq!(ElementSub::sub(&[1u32], &[1]), (vec![0], false),);
     }
 
@@ -139,5 +162,31 @@ mod tests {
         assert_eq!(ElementSub::sub(&[0u32, 1], &[0, 1]), (vec![0], false),);
         assert_eq!(ElementSub::sub(&[1u32, 1], &[1]), (vec![0, 1], false),);
         assert_eq!(ElementSub::sub(&[1u32, 1], &[0, 1]), (vec![1], false),);
+        assert_eq!(ElementSub::sub(&[0u32, 1], &[0, 2]), (vec![0, 1], true),);
+        assert_eq!(ElementSub::sub(&[1u32], &[0, 1]), (vec![u32::MAX], true),);
+    }
+
+    #[test]
+    fn test_sub_assign_borrow() {
+        let mut slice = [0u8, 1];
+        assert!(!ElementSub::sub_assign_borrow(&mut slice, &[1]));
+        assert_eq!(slice, [u8::MAX, 0]);
+
+        let mut slice = [0u8, 0];
+        assert!(ElementSub::sub_assign_borrow(&mut slice, &[1]));
+        assert_eq!(slice, [u8::MAX, u8::MAX]);
+
+        let mut slice = [0u8, 1];
+        assert!(!ElementSub::sub_assign_borrow(&mut slice, &[1, 0]));
+        assert_eq!(slice, [u8::MAX, 0]);
+        let mut slice = [0u8, 1];
+        assert!(ElementSub::sub_assign_borrow(&mut slice, &[1, 1]));
+        assert_eq!(slice, [u8::MAX, u8::MAX]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sub_assign_borrow_long() {
+        ElementSub::sub_assign_borrow(&mut [1u8], &[0, 1]);
     }
 }
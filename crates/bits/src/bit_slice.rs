@@ -1,15 +1,21 @@
 //! A type for bitwise operations on slices of integers
 
+use crate::algos::ElementCmp;
+use core::cmp::Ordering;
 use core::mem;
+use core::ops::Range;
+use numeric_traits::cast::FromTruncating;
 use numeric_traits::class::{BoundedBit, Integral};
 use numeric_traits::identity::{One, Zero};
 use numeric_traits::ops::overflowing::OverflowingOps;
 
 mod iter;
+mod range;
 
 pub use iter::*;
 use numeric_traits::ops::core::{BitAssignOps, NumAssignOps};
 use numeric_traits::ops::widening::WideningMul;
+pub use range::*;
 
 #[inline]
 fn idx_bit<T: ?Sized + BitSliceExt>(idx: usize) -> (usize, usize) {
@@ -23,6 +29,11 @@ pub trait BitLike:
 {
     /// The length of this type in bits.
     const BIT_LEN: usize;
+
+    /// The number of 1 bits, starting from the LSB
+    fn trailing_ones(self) -> usize;
+    /// The number of 1 bits, starting from the MSB
+    fn leading_ones(self) -> usize;
 }
 
 impl<
@@ -35,8 +46,20 @@ impl<
             + Ord
             + Copy,
     > BitLike for T
+where
+    usize: FromTruncating<T>,
 {
     const BIT_LEN: usize = mem::size_of::<T>() * 8;
+
+    #[inline]
+    fn trailing_ones(self) -> usize {
+        usize::truncate_from(BoundedBit::trailing_zeros(!self))
+    }
+
+    #[inline]
+    fn leading_ones(self) -> usize {
+        usize::truncate_from(BoundedBit::leading_zeros(!self))
+    }
 }
 
 /// Trait for things that can be considered slices of bits. This includes slices obviously, as well
@@ -69,6 +92,16 @@ pub trait BitSliceExt: core::fmt::Debug {
         self.len() * Self::Bit::BIT_LEN
     }
 
+    /// Get the number of elements in this slice, ignoring any zero elements at the high end. This
+    /// is the length the slice would have after [`IntSlice::shrink`](crate::utils::IntSlice),
+    /// except that an all-zero slice has a significant length of `0`.
+    fn significant_len(&self) -> usize {
+        self.slice()
+            .iter()
+            .rposition(|&val| val != Self::Bit::zero())
+            .map_or(0, |idx| idx + 1)
+    }
+
     /// Get the value of an element at a given index, panicking if the index is out of range
     fn get(&self, idx: usize) -> Self::Bit {
         self.get_opt(idx).expect("get index in-bounds")
@@ -139,6 +172,33 @@ pub trait BitSliceExt: core::fmt::Debug {
         })
     }
 
+    /// Set a run of `count` bits starting at bit `start` to the low `count` bits of `val`,
+    /// panicking if the run extends out of range
+    ///
+    /// # Panics
+    ///
+    /// If `count` is larger than the bit length of `V`, or the run of bits is outside the range
+    /// of this slice
+    fn set_bits<V: BitLike>(&mut self, start: usize, count: usize, val: V) {
+        assert!(
+            count <= V::BIT_LEN,
+            "Attempt to write {} bits from a {} bit value",
+            count,
+            V::BIT_LEN,
+        );
+        assert!(
+            start + count <= self.bit_len(),
+            "Attempt to write bits {}..{} out of bounds",
+            start,
+            start + count,
+        );
+
+        for offset in 0..count {
+            let bit = (val >> offset) & V::one() != V::zero();
+            self.set_bit(start + offset, bit);
+        }
+    }
+
     /// Set a single value by index on this slice, doing nothing if the index is out of range
     #[inline]
     fn set_ignore(&mut self, pos: usize, val: Self::Bit) {
@@ -151,10 +211,55 @@ pub trait BitSliceExt: core::fmt::Debug {
         let _ = self.set_bit_opt(pos, val);
     }
 
+    /// Get the number of consecutive set bits, starting from the least significant bit
+    fn trailing_ones(&self) -> usize {
+        let mut out = 0;
+        for &item in self.slice() {
+            let ones = item.trailing_ones();
+            out += ones;
+            if ones != Self::Bit::BIT_LEN {
+                break;
+            }
+        }
+        out
+    }
+
+    /// Get the number of consecutive set bits, starting from the most significant bit
+    fn leading_ones(&self) -> usize {
+        let mut out = 0;
+        for &item in self.slice().iter().rev() {
+            let ones = item.leading_ones();
+            out += ones;
+            if ones != Self::Bit::BIT_LEN {
+                break;
+            }
+        }
+        out
+    }
+
     /// Get an iterator over the bit values of this slice
     fn iter_bits(&self) -> BitIter<'_, Self::Bit> {
         BitIter::new(self.slice())
     }
+
+    /// Get a view over the bits `range` of this slice, without copying. The range doesn't need to
+    /// start or end on an element boundary.
+    ///
+    /// # Panics
+    ///
+    /// If `range` extends past the end of this slice
+    fn as_bit_slice(&self, range: Range<usize>) -> BitRange<'_, Self::Bit> {
+        BitRange::new(self.slice(), range)
+    }
+
+    /// Compare this slice to another as unsigned integers, with the shorter one treated as
+    /// zero-extended. See [`ElementCmp::cmp`].
+    fn cmp_slice<T>(&self, other: &T) -> Ordering
+    where
+        T: ?Sized + BitSliceExt<Bit = Self::Bit>,
+    {
+        ElementCmp::cmp(self, other)
+    }
 }
 
 impl<I: BitLike> BitSliceExt for [I] {
@@ -185,7 +290,7 @@ impl<I: BitLike, const N: usize> BitSliceExt for [I; N] {
     }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<I: BitLike> BitSliceExt for alloc::vec::Vec<I> {
     type Bit = I;
 
@@ -200,6 +305,64 @@ impl<I: BitLike> BitSliceExt for alloc::vec::Vec<I> {
     }
 }
 
+/// Read-only access to the elements of something that can be considered a slice of bits. This is
+/// implemented for every [`BitSliceExt`], as well as for [`BitRange`] views, which can't provide a
+/// contiguous slice of their elements. Algorithms take their right-hand operand through this
+/// trait, so a view can be passed to them without copying it.
+///
+/// Methods are named separately from those on [`BitSliceExt`], so that having both traits in scope
+/// doesn't make calls ambiguous.
+pub trait BitSliceRead: core::fmt::Debug {
+    /// The bit container type contained in this slice
+    type Bit: BitLike;
+
+    /// Get the number of [`Self::Bit`] elements needed to hold the bits of this slice
+    fn elem_len(&self) -> usize;
+
+    /// Get the value of an element at a given index, returning `None` if the index is out of
+    /// range
+    fn read_elem(&self, idx: usize) -> Option<Self::Bit>;
+
+    /// Get the length of this slice in bits
+    fn read_bit_len(&self) -> usize {
+        self.elem_len() * Self::Bit::BIT_LEN
+    }
+
+    /// Get the value of a bit at a given index, returning `None` if the index is out of range
+    fn read_bit(&self, idx: usize) -> Option<bool> {
+        if idx >= self.read_bit_len() {
+            return None;
+        }
+        let (idx, bit) = (idx / Self::Bit::BIT_LEN, idx % Self::Bit::BIT_LEN);
+        self.read_elem(idx)
+            .map(|val| val & (Self::Bit::one() << bit) != Self::Bit::zero())
+    }
+}
+
+impl<T: ?Sized + BitSliceExt> BitSliceRead for T {
+    type Bit = T::Bit;
+
+    #[inline]
+    fn elem_len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn read_elem(&self, idx: usize) -> Option<Self::Bit> {
+        self.get_opt(idx)
+    }
+
+    #[inline]
+    fn read_bit_len(&self) -> usize {
+        self.bit_len()
+    }
+
+    #[inline]
+    fn read_bit(&self, idx: usize) -> Option<bool> {
+        self.get_bit_opt(idx)
+    }
+}
+
 /// Trait for things that can be considered growable vectors of bits. This includes vectors and
 /// any vector-like object.
 pub trait BitVecExt: BitSliceExt {
@@ -218,9 +381,24 @@ pub trait BitVecExt: BitSliceExt {
         self.extend(len + 1, Self::Bit::zero());
         self.set_bit_ignore(idx, val);
     }
+
+    /// Build a new value from an iterator of bits, in little-endian bit order. The result is
+    /// padded with zero bits up to a whole number of [`Self::Bit`]s. This is the inverse of
+    /// [`BitSliceExt::iter_bits`].
+    fn from_bits<It>(bits: It) -> Self
+    where
+        Self: Sized + Default,
+        It: IntoIterator<Item = bool>,
+    {
+        let mut out = Self::default();
+        for (idx, bit) in bits.into_iter().enumerate() {
+            out.set_bit_push(idx, bit);
+        }
+        out
+    }
 }
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 impl<I: BitLike> BitVecExt for alloc::vec::Vec<I> {
     fn extend(&mut self, len: usize, val: Self::Bit) {
         if len > self.len() {
@@ -233,6 +411,7 @@ impl<I: BitLike> BitVecExt for alloc::vec::Vec<I> {
 mod tests {
     use super::*;
     use alloc::vec;
+    use alloc::vec::Vec;
 
     #[test]
     fn test_idx() {
@@ -243,6 +422,15 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_significant_len() {
+        assert_eq!([1u8, 0, 0].significant_len(), 1);
+        assert_eq!([0u8, 0].significant_len(), 0);
+        assert_eq!([0u8, 0, 1].significant_len(), 3);
+        assert_eq!([0u16, 5, 0].significant_len(), 2);
+        assert_eq!(<[u32]>::significant_len(&[]), 0);
+    }
+
     #[test]
     fn test_get_bit() {
         let slice = &[0b1010101010101010u16, 0b1010101010101010];
@@ -261,6 +449,122 @@ mod tests {
         assert_eq!(slice, &[0b1010101010101011, 0b0010101010101010])
     }
 
+    #[test]
+    fn test_set_bits() {
+        let mut data = [0x0Fu8, 0x00, 0xFF];
+        data.set_bits(4, 12, 0xABCu16);
+        assert_eq!(data, [0xCF, 0xAB, 0xFF]);
+
+        let mut data = [0xFFu8, 0xFF];
+        data.set_bits(6, 4, 0b0110u8);
+        assert_eq!(data, [0b1011_1111, 0b1111_1101]);
+
+        let mut data = [0u32; 2];
+        data.set_bits(0, 0, 0xFFFFu32);
+        assert_eq!(data, [0, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_bits_oob() {
+        let mut data = [0u8; 2];
+        data.set_bits(8, 12, 0u16);
+    }
+
+    #[test]
+    fn test_ones() {
+        let slice = &[0xFFu8, 0x0F];
+        assert_eq!(slice.trailing_ones(), 12);
+        assert_eq!(slice.leading_ones(), 0);
+
+        let slice = &[0x0Fu8, 0xFF];
+        assert_eq!(slice.trailing_ones(), 4);
+        assert_eq!(slice.leading_ones(), 8);
+
+        let slice = &[0xFFu8, 0xF0];
+        assert_eq!(slice.trailing_ones(), 8);
+        assert_eq!(slice.leading_ones(), 4);
+
+        let slice = &[u16::MAX, u16::MAX];
+        assert_eq!(slice.trailing_ones(), 32);
+        assert_eq!(slice.leading_ones(), 32);
+
+        let slice: &[u32] = &[];
+        assert_eq!(slice.trailing_ones(), 0);
+        assert_eq!(slice.leading_ones(), 0);
+    }
+
+    #[test]
+    fn test_as_bit_slice() {
+        let slice = [0x12u8, 0x34, 0x56, 0x78];
+
+        let high = slice.as_bit_slice(16..32);
+        assert_eq!(high.bit_len(), 16);
+        assert_eq!(high.len(), 2);
+        assert_eq!(high.to_vec(), [0x56, 0x78]);
+        for idx in 0..16 {
+            assert_eq!(high.get_bit(idx), slice.get_bit(idx + 16));
+        }
+        assert_eq!(high.get_bit_opt(16), None);
+
+        // Not aligned to an element
+        let mid = slice.as_bit_slice(4..24);
+        assert_eq!(mid.to_vec(), [0x41, 0x63, 0x05]);
+        assert_eq!(mid.get_opt(3), None);
+        assert!(mid.iter_bits().eq((4..24).map(|idx| slice.get_bit(idx))));
+
+        // Views of views
+        let (low, high) = mid.split_at(10);
+        assert_eq!(low.to_vec(), [0x41, 0x03]);
+        assert_eq!(high.to_vec(), [0x58, 0x01]);
+        assert_eq!(high.as_bit_slice(1..9).to_vec(), [0xAC]);
+
+        let empty = slice.as_bit_slice(8..8);
+        assert!(empty.is_empty());
+        assert_eq!(empty.to_vec(), []);
+    }
+
+    #[test]
+    fn test_bit_range_algos() {
+        use crate::algos::{BitwiseAdd, ElementAdd, ElementCmp, ElementMul};
+
+        let slice = [0x12u8, 0x34, 0x56, 0x78];
+        // Bits 4..24 are 0x05_6341, not aligned to an element
+        let mid = slice.as_bit_slice(4..24);
+        assert_eq!(ElementAdd::add(&[0xFFu8], &mid), [0x40, 0x64, 0x05]);
+        assert_eq!(BitwiseAdd::add(&[0x01u8], &mid), [0x42, 0x63, 0x05]);
+        assert_eq!(
+            ElementCmp::cmp(&[0x41u8, 0x63, 0x05], &mid),
+            Ordering::Equal
+        );
+        assert_eq!(
+            ElementMul::mul(&[0x12u8, 0x34], &mid),
+            [0x92, 0x2E, 0x8A, 0x18, 0x01]
+        );
+
+        // Multiplying the halves of a value, as Karatsuba would, without copying either
+        let (low, high) = slice.as_bit_slice(0..32).split_at(16);
+        let mut out = [0u8; 4];
+        ElementAdd::add_wrapping(&mut out, &low);
+        ElementMul::mul_wrapping(&mut out, &high);
+        assert_eq!(out, 0x1879_EE0Cu32.to_le_bytes());
+    }
+
+    #[test]
+    #[should_panic = "Bit range 8..33 out of bounds"]
+    fn test_as_bit_slice_oob() {
+        let _ = [0u8; 4].as_bit_slice(8..33);
+    }
+
+    #[test]
+    fn test_cmp_slice() {
+        assert_eq!([1u32].cmp_slice(&[1, 0]), Ordering::Equal);
+        assert_eq!([1u32, 0].cmp_slice(&[1]), Ordering::Equal);
+        assert_eq!([2u32].cmp_slice(&[1]), Ordering::Greater);
+        assert_eq!([1u32].cmp_slice(&[2]), Ordering::Less);
+        assert_eq!([1u32].cmp_slice(&vec![0, 1]), Ordering::Less);
+    }
+
     #[test]
     fn test_extend() {
         let mut data = vec![0u8; 1];
@@ -271,4 +575,22 @@ mod tests {
         BitVecExt::extend(&mut data, 1, 0);
         assert_eq!(&data, &[0, 1]);
     }
+
+    #[test]
+    fn test_from_bits() {
+        let data = vec![0xDEAD_BEEFu32, 0x0123_4567, 0x8000_0001];
+        let bits = data.iter_bits().collect::<Vec<_>>();
+        assert_eq!(bits.len(), 96);
+        let mut iter = data.iter_bits();
+        iter.nth(39);
+        assert_eq!(iter.len(), 56);
+        assert_eq!(Vec::<u32>::from_bits(bits), data);
+
+        let data: Vec<u8> =
+            BitVecExt::from_bits([true, false, true, true, false, false, false, false, true]);
+        assert_eq!(data, [0b0000_1101, 0b1]);
+
+        let data: Vec<u16> = BitVecExt::from_bits([]);
+        assert!(data.is_empty());
+    }
 }
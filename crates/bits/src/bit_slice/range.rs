@@ -0,0 +1,181 @@
+use crate::bit_slice::{BitLike, BitSliceRead};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// A view over a range of bits in a slice, which doesn't need to start or end on an element
+/// boundary. Elements read through the view are shifted so the first bit of the range is bit 0,
+/// and bits past the end of the range read as zero. See `BitSliceExt::as_bit_slice`.
+///
+/// This can't implement `BitSliceExt` itself, as that exposes the underlying elements directly.
+/// Instead it implements [`BitSliceRead`], so it can be used as the right-hand side of the slice
+/// algorithms without copying. Use [`BitRange::to_vec`] to get an owned copy.
+#[derive(Debug)]
+pub struct BitRange<'a, I> {
+    slice: &'a [I],
+    offset: usize,
+    len: usize,
+}
+
+impl<I> Clone for BitRange<'_, I> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I> Copy for BitRange<'_, I> {}
+
+impl<'a, I: BitLike> BitRange<'a, I> {
+    pub(super) fn new(slice: &'a [I], range: Range<usize>) -> BitRange<'a, I> {
+        assert!(
+            range.start <= range.end && range.end <= slice.len() * I::BIT_LEN,
+            "Bit range {}..{} out of bounds",
+            range.start,
+            range.end,
+        );
+        let first = range.start / I::BIT_LEN;
+        let last = range.end.div_ceil(I::BIT_LEN);
+        BitRange {
+            slice: &slice[first..last],
+            offset: range.start % I::BIT_LEN,
+            len: range.end - range.start,
+        }
+    }
+
+    /// Get the length of this view in bits
+    #[must_use]
+    pub fn bit_len(&self) -> usize {
+        self.len
+    }
+
+    /// Get the number of elements needed to hold the bits of this view
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len.div_ceil(I::BIT_LEN)
+    }
+
+    /// Whether this view contains no bits
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get an element of this view, panicking if the index is out of range
+    ///
+    /// # Panics
+    ///
+    /// If `idx` is outside the range of this view
+    #[must_use]
+    pub fn get(&self, idx: usize) -> I {
+        self.get_opt(idx).expect("get index in-bounds")
+    }
+
+    /// Get an element of this view, returning `None` if the index is out of range
+    #[must_use]
+    pub fn get_opt(&self, idx: usize) -> Option<I> {
+        if idx >= self.len() {
+            return None;
+        }
+
+        let low = self.slice[idx] >> self.offset;
+        let high = match self.slice.get(idx + 1) {
+            Some(&high) if self.offset != 0 => high << (I::BIT_LEN - self.offset),
+            _ => I::zero(),
+        };
+        let val = low | high;
+
+        let remaining = self.len - idx * I::BIT_LEN;
+        if remaining < I::BIT_LEN {
+            Some(val & ((I::one() << remaining) - I::one()))
+        } else {
+            Some(val)
+        }
+    }
+
+    /// Get the value of a bit in this view, panicking if the index is out of range
+    ///
+    /// # Panics
+    ///
+    /// If `idx` is outside the range of this view
+    #[must_use]
+    pub fn get_bit(&self, idx: usize) -> bool {
+        self.get_bit_opt(idx).expect("get_bit index in-bounds")
+    }
+
+    /// Get the value of a bit in this view, returning `None` if the index is out of range
+    #[must_use]
+    pub fn get_bit_opt(&self, idx: usize) -> Option<bool> {
+        if idx >= self.len {
+            return None;
+        }
+        let idx = idx + self.offset;
+        let val = self.slice[idx / I::BIT_LEN];
+        Some(val & (I::one() << (idx % I::BIT_LEN)) != I::zero())
+    }
+
+    /// Get a view over a range of bits within this one
+    ///
+    /// # Panics
+    ///
+    /// If `range` extends past the end of this view
+    #[must_use]
+    pub fn as_bit_slice(&self, range: Range<usize>) -> BitRange<'a, I> {
+        assert!(
+            range.start <= range.end && range.end <= self.len,
+            "Bit range {}..{} out of bounds",
+            range.start,
+            range.end,
+        );
+        BitRange::new(
+            self.slice,
+            range.start + self.offset..range.end + self.offset,
+        )
+    }
+
+    /// Split this view into two at a bit index, the first containing the bits `0..mid`, and
+    /// the second `mid..`
+    ///
+    /// # Panics
+    ///
+    /// If `mid` is past the end of this view
+    #[must_use]
+    pub fn split_at(&self, mid: usize) -> (BitRange<'a, I>, BitRange<'a, I>) {
+        (self.as_bit_slice(0..mid), self.as_bit_slice(mid..self.len))
+    }
+
+    /// Get an iterator over the bit values of this view
+    pub fn iter_bits(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(|idx| self.get_bit(idx))
+    }
+
+    /// Copy the bits of this view into a new vector of elements
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<I> {
+        (0..self.len()).map(|idx| self.get(idx)).collect()
+    }
+}
+
+impl<I: BitLike> BitSliceRead for BitRange<'_, I> {
+    type Bit = I;
+
+    #[inline]
+    fn elem_len(&self) -> usize {
+        self.len()
+    }
+
+    #[inline]
+    fn read_elem(&self, idx: usize) -> Option<I> {
+        self.get_opt(idx)
+    }
+
+    #[inline]
+    fn read_bit_len(&self) -> usize {
+        self.bit_len()
+    }
+
+    #[inline]
+    fn read_bit(&self, idx: usize) -> Option<bool> {
+        self.get_bit_opt(idx)
+    }
+}
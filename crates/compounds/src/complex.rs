@@ -1,9 +1,16 @@
-use core::ops::{Add, Div, Mul, Sub};
+use core::fmt::{self, Write};
+use core::ops::{Add, Div, Mul, Rem, Sub};
 
 use numeric_traits::class::Real;
 use numeric_traits::identity::{One, Zero};
 use numeric_traits::ops::core::NumOps;
+use numeric_traits::ops::{ExpOps, Pow, TrigOps};
 
+/// A complex number, with a real and imaginary part.
+///
+/// Complex numbers have no natural ordering, so `Complex` doesn't implement `PartialOrd`, and as
+/// such isn't [`Numeric`](numeric_traits::class::Numeric). It still provides [`Zero`], [`One`], the
+/// arithmetic operators, and, over real components, [`Pow`].
 #[derive(Default, Clone, PartialEq)]
 pub struct Complex<T> {
     real: T,
@@ -50,6 +57,38 @@ impl<T: Real> Complex<T> {
     }
 }
 
+impl<T: Real + TrigOps> Complex<T> {
+    /// Create a complex number from its polar form, a magnitude and an angle in radians
+    pub fn from_polar(abs: T, arg: T) -> Complex<T> {
+        Complex::new(abs.clone() * arg.clone().cos(), abs * arg.sin())
+    }
+
+    /// Get the argument of this number, the angle in radians between it and the positive real
+    /// axis. This will be in the range `[-π, π]`.
+    pub fn arg(&self) -> T {
+        self.imag.clone().atan2(self.real.clone())
+    }
+
+    /// Raise this number to a real power, using its polar form
+    pub fn powf(self, n: T) -> Complex<T> {
+        let arg = self.arg();
+        Complex::from_polar(self.abs().pow(n.clone()), arg * n)
+    }
+}
+
+impl<T: Real + TrigOps + ExpOps> Complex<T> {
+    /// Raise `e` to the power of this number, `e^a * (cos b + i sin b)` for `a + bi`
+    pub fn exp(self) -> Complex<T> {
+        Complex::from_polar(self.real.exp(), self.imag)
+    }
+
+    /// The principal natural logarithm of this number, the inverse of [`Complex::exp`]. The
+    /// imaginary part of the result will be in the range `[-π, π]`.
+    pub fn ln(self) -> Complex<T> {
+        Complex::new(self.abs().ln(), self.arg())
+    }
+}
+
 impl<T> Add for Complex<T>
 where
     T: Add,
@@ -151,7 +190,86 @@ where
     }
 }
 
-// TODO: Rem and Pow
+/// The remainder of truncated division, `self - (self / rhs) * rhs`. For real components this is
+/// always zero (barring rounding), for integer components it's the remainder of dividing Gaussian
+/// integers with each part of the quotient rounded toward zero.
+impl<T> Rem for Complex<T>
+where
+    T: NumOps + Clone,
+{
+    type Output = Complex<T>;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        let quot = self.clone() / rhs.clone();
+        self - quot * rhs
+    }
+}
+
+/// Raise this number to a complex power, using the principal logarithm as `e^(rhs * ln(self))`.
+/// Zero to the power of zero is one, and zero to any other power is zero.
+impl<T: Real + TrigOps + ExpOps> Pow for Complex<T> {
+    type Output = Complex<T>;
+
+    fn pow(self, rhs: Self) -> Self::Output {
+        if self.is_zero() {
+            return if rhs.is_zero() {
+                Complex::one()
+            } else {
+                Complex::zero()
+            };
+        }
+        (rhs * self.ln()).exp()
+    }
+}
+
+/// Writes the imaginary part of a complex number, replacing its leading sign with a spaced `+` or
+/// `-` separator
+struct ImagWriter<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    started: bool,
+}
+
+impl fmt::Write for ImagWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.started || s.is_empty() {
+            return self.f.write_str(s);
+        }
+        self.started = true;
+        match s.strip_prefix('-') {
+            Some(rest) => {
+                self.f.write_str(" - ")?;
+                self.f.write_str(rest)
+            }
+            None => {
+                self.f.write_str(" + ")?;
+                self.f.write_str(s)
+            }
+        }
+    }
+}
+
+/// Displays the number as `a + bi` or `a - bi`. The imaginary part is left off when it's zero, but
+/// the real part is always shown, so `i` displays as `0 + 1i`. Precision, if provided, is applied
+/// to both parts.
+impl<T: fmt::Display + Zero> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        match precision {
+            Some(p) => write!(f, "{:.*}", p, self.real)?,
+            None => write!(f, "{}", self.real)?,
+        }
+        if self.imag.is_zero() {
+            return Ok(());
+        }
+
+        let mut imag = ImagWriter { f, started: false };
+        match precision {
+            Some(p) => write!(imag, "{:.*}", p, self.imag)?,
+            None => write!(imag, "{}", self.imag)?,
+        }
+        f.write_str("i")
+    }
+}
 
 impl<T: PartialEq + Zero> Zero for Complex<T> {
     fn zero() -> Self {
@@ -175,3 +293,105 @@ impl<T: PartialEq + Zero + One> One for Complex<T> {
         *self == Self::one()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn approx_eq(a: &Complex<f64>, b: &Complex<f64>) -> bool {
+        (a.real - b.real).abs() < 1e-10 && (a.imag - b.imag).abs() < 1e-10
+    }
+
+    #[test]
+    fn test_identities() {
+        fn sum<T: Zero + Add<Output = T>>(vals: impl IntoIterator<Item = T>) -> T {
+            vals.into_iter().fold(T::zero(), |acc, val| acc + val)
+        }
+
+        let vals = [
+            Complex::new(1.0, 2.0),
+            Complex::new(-3.5, 0.5),
+            Complex::from_imag(4.0),
+        ];
+        assert!(approx_eq(&sum(vals), &Complex::new(-2.5, 6.5)));
+        assert!(sum::<Complex<f64>>([]).is_zero());
+        assert!(Complex::<f64>::one().is_one());
+
+        let a = Complex::new(3.0, -1.0);
+        let b = Complex::new(0.5, 2.0);
+        assert!(approx_eq(&(a.clone() % b.clone()), &Complex::zero()));
+
+        let i = Complex::from_imag(1.0);
+        assert!(approx_eq(
+            &i.clone().pow(Complex::from_real(2.0)),
+            &Complex::from_real(-1.0)
+        ));
+        // i^i is real, e^(-π/2)
+        let expected = (-core::f64::consts::FRAC_PI_2).exp();
+        assert!(approx_eq(
+            &i.clone().pow(i.clone()),
+            &Complex::from_real(expected)
+        ));
+        assert!(Complex::<f64>::zero().pow(Complex::zero()).is_one());
+        assert!(Complex::<f64>::zero().pow(i).is_zero());
+    }
+
+    #[test]
+    fn test_gaussian_rem() {
+        // (7 + 3i) / (2 + i) = 3.4 - 0.2i, which truncates to 3
+        let rem = Complex::new(7, 3) % Complex::new(2, 1);
+        assert_eq!((rem.real, rem.imag), (1, 0));
+    }
+
+    #[test]
+    fn test_display() {
+        extern crate alloc;
+        use alloc::format;
+
+        assert_eq!(format!("{}", Complex::new(1, 2)), "1 + 2i");
+        assert_eq!(format!("{}", Complex::new(3, -4)), "3 - 4i");
+        assert_eq!(format!("{}", Complex::new(0, 1)), "0 + 1i");
+        assert_eq!(format!("{}", Complex::new(-5, 0)), "-5");
+        assert_eq!(format!("{}", Complex::new(0, 0)), "0");
+
+        assert_eq!(format!("{:.2}", Complex::new(1.5, -0.25)), "1.50 - 0.25i");
+        assert_eq!(format!("{:.1}", Complex::new(2.0, 0.0)), "2.0");
+        assert_eq!(format!("{}", Complex::new(0.5, 1.0)), "0.5 + 1i");
+    }
+
+    #[test]
+    fn test_exp_ln() {
+        let vals = [
+            Complex::new(1., 0.),
+            Complex::new(0., 1.),
+            Complex::new(-2., 0.5),
+            Complex::new(3., -4.),
+            Complex::new(-0.25, -7.),
+        ];
+        for z in vals {
+            assert!(approx_eq(&z.clone().ln().exp(), &z));
+        }
+
+        let pi = core::f64::consts::PI;
+        assert!(approx_eq(
+            &Complex::new(0., pi).exp(),
+            &Complex::new(-1., 0.)
+        ));
+        assert!(approx_eq(
+            &Complex::new(-1., 0.).ln(),
+            &Complex::new(0., pi)
+        ));
+    }
+
+    #[test]
+    fn test_powf() {
+        let z = Complex::new(3., -4.);
+        assert!(approx_eq(&z.clone().powf(2.), &(z.clone() * z.clone())));
+        assert!(approx_eq(&z.clone().powf(0.5).powf(2.), &z));
+        assert!(approx_eq(&z.powf(0.), &Complex::one()));
+        assert!(approx_eq(
+            &Complex::new(-4., 0.).powf(0.5),
+            &Complex::new(0., 2.)
+        ));
+    }
+}
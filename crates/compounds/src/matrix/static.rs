@@ -1,5 +1,7 @@
 use crate::vector::Vector;
 use core::array;
+use core::cmp::Ordering;
+use core::fmt::{self, Write};
 use core::ops::{Add, Index, IndexMut, Mul, Sub};
 use core::ptr::NonNull;
 use numeric_static_iter::{zip_all, IntoStaticIter, StaticIter};
@@ -16,6 +18,14 @@ impl<T, const ROW: usize, const COL: usize> Matrix<T, ROW, COL> {
         Matrix(rows)
     }
 
+    /// Create a new matrix by calling a function with the `(row, column)` of each element
+    pub fn from_fn<F>(mut f: F) -> Matrix<T, ROW, COL>
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Matrix(array::from_fn(|i| array::from_fn(|j| f(i, j))))
+    }
+
     pub(crate) fn as_ptr(&self) -> NonNull<T> {
         NonNull::from(&self.0).cast()
     }
@@ -43,6 +53,27 @@ impl<T, const ROW: usize, const COL: usize> Matrix<T, ROW, COL> {
     pub fn swap_rows(&mut self, a: usize, b: usize) {
         self.0.swap(a, b)
     }
+
+    /// Get a reference to a single row of this matrix
+    ///
+    /// # Panics
+    ///
+    /// If `row` is outside the range of this matrix
+    pub fn row(&self, row: usize) -> &[T; COL] {
+        &self.0[row]
+    }
+
+    /// Get a copy of a single column of this matrix
+    ///
+    /// # Panics
+    ///
+    /// If `col` is outside the range of this matrix
+    pub fn col(&self, col: usize) -> Vector<T, ROW>
+    where
+        T: Clone,
+    {
+        array::from_fn(|row| self.0[row][col].clone()).into()
+    }
 }
 
 impl<T: RealSigned, const ROW: usize, const COL: usize> Matrix<T, ROW, COL> {
@@ -73,6 +104,114 @@ impl<T: RealSigned, const N: usize> SquareMatrix<T, N> {
             }
         }
     }
+
+    /// Get the inverse of this matrix, such that multiplying the two gives the identity matrix,
+    /// or `None` if the matrix is singular (its determinant is zero), or a pivot candidate is
+    /// unordered, such as a NaN.
+    ///
+    /// This uses Gauss-Jordan elimination with partial pivoting, so the result of a float matrix
+    /// may have small rounding errors.
+    pub fn inverse(&self) -> Option<SquareMatrix<T, N>> {
+        let mut left = self.clone();
+        let mut right = SquareMatrix::<T, N>::one();
+
+        for col in 0..N {
+            // Pick the largest value in the column as the pivot, to limit rounding error. If there
+            // are no non-zero values, the matrix is singular.
+            let mut best: Option<(usize, T)> = None;
+            for r in col..N {
+                let val = left[(r, col)].clone().abs();
+                // Values that can't be ordered, like NaN, leave no meaningful pivot
+                if val.partial_cmp(&T::zero())? == Ordering::Equal {
+                    continue;
+                }
+                match &best {
+                    Some((_, max)) if val <= *max => (),
+                    _ => best = Some((r, val)),
+                }
+            }
+            let (pivot, _) = best?;
+
+            if pivot != col {
+                left.swap_rows(pivot, col);
+                right.swap_rows(pivot, col);
+            }
+
+            // Scale the pivot row so the pivot is one
+            let top = left[(col, col)].clone();
+            for c in 0..N {
+                left[(col, c)] = left[(col, c)].clone() / top.clone();
+                right[(col, c)] = right[(col, c)].clone() / top.clone();
+            }
+
+            // Remove the pivot column from every other row
+            for r in 0..N {
+                let factor = left[(r, col)].clone();
+                if r == col || factor.is_zero() {
+                    continue;
+                }
+                for c in 0..N {
+                    left[(r, c)] = left[(r, c)].clone() - left[(col, c)].clone() * factor.clone();
+                    right[(r, c)] =
+                        right[(r, c)].clone() - right[(col, c)].clone() * factor.clone();
+                }
+            }
+        }
+
+        Some(right)
+    }
+}
+
+/// Counts the characters written to it, used to align matrix columns without allocating
+struct WidthCounter(usize);
+
+impl fmt::Write for WidthCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Displays the matrix with one row per line, and each column right-aligned. Precision, if
+/// provided, is applied to every element.
+impl<T, const ROW: usize, const COL: usize> fmt::Display for Matrix<T, ROW, COL>
+where
+    T: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        let widths: [usize; COL] = array::from_fn(|j| {
+            (0..ROW)
+                .map(|i| {
+                    let mut counter = WidthCounter(0);
+                    let _ = match precision {
+                        Some(p) => write!(counter, "{:.*}", p, self.0[i][j]),
+                        None => write!(counter, "{}", self.0[i][j]),
+                    };
+                    counter.0
+                })
+                .max()
+                .unwrap_or(0)
+        });
+
+        for (i, row) in self.0.iter().enumerate() {
+            if i != 0 {
+                writeln!(f)?;
+            }
+            write!(f, "[")?;
+            for (j, val) in row.iter().enumerate() {
+                if j != 0 {
+                    write!(f, ", ")?;
+                }
+                match precision {
+                    Some(p) => write!(f, "{:>w$.*}", p, val, w = widths[j])?,
+                    None => write!(f, "{:>w$}", val, w = widths[j])?,
+                }
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
 }
 
 impl<T, const ROW: usize, const COL: usize> Default for Matrix<T, ROW, COL>
@@ -198,6 +337,28 @@ impl<T, const ROW: usize, const COL: usize> IndexMut<(usize, usize)> for Matrix<
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from_fn() {
+        let a = Matrix::<usize, 3, 4>::from_fn(|i, j| i * 4 + j);
+
+        let expected = Matrix::new([[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11]]);
+        assert_eq!(a, expected);
+        assert_eq!(a.row(1), &[4, 5, 6, 7]);
+        assert_eq!(a.col(2), Vector::new([2, 6, 10]));
+    }
+
+    #[test]
+    fn test_display() {
+        extern crate alloc;
+        use alloc::format;
+
+        let a = Matrix::new([[1, 20, 3], [-4, 5, 600]]);
+        assert_eq!(format!("{}", a), "[ 1, 20,   3]\n[-4,  5, 600]");
+
+        let b = Matrix::new([[1.0, 0.25], [10.5, 2.0]]);
+        assert_eq!(format!("{:.1}", b), "[ 1.0, 0.2]\n[10.5, 2.0]");
+    }
+
     #[test]
     fn test_mul() {
         let a = Matrix::new([[1, 2, 3], [4, 5, 6]]);
@@ -240,4 +401,49 @@ mod tests {
         let b = Matrix::<f64, 3, 3>::new([[2., -3., 1.], [2., 0., -1.], [1., 4., 5.]]);
         assert_eq!(b.determinant().round(), 49.);
     }
+
+    fn assert_approx<const N: usize>(a: SquareMatrix<f64, N>, b: SquareMatrix<f64, N>) {
+        for i in 0..N {
+            for j in 0..N {
+                assert!((a[(i, j)] - b[(i, j)]).abs() < 1e-10, "{a:?} != {b:?}");
+            }
+        }
+    }
+
+    fn assert_identity<const N: usize>(m: SquareMatrix<f64, N>) {
+        assert_approx(m, SquareMatrix::one());
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = Matrix::new([[4., 7.], [2., 6.]]);
+        let inv = a.inverse().unwrap();
+        assert_approx(inv.clone(), Matrix::new([[0.6, -0.7], [-0.2, 0.4]]));
+        assert_identity(a.clone() * inv.clone());
+        assert_identity(inv * a);
+
+        // Needs a row swap, as the first pivot is zero
+        let b = Matrix::<f64, 3, 3>::new([[0., 2., -1.], [3., 0., 1.], [1., 4., 5.]]);
+        let inv = b.inverse().unwrap();
+        assert_identity(b.clone() * inv.clone());
+        assert_identity(inv * b);
+    }
+
+    #[test]
+    fn test_inverse_singular() {
+        let a = Matrix::new([[1., 2.], [2., 4.]]);
+        assert_eq!(a.inverse(), None);
+
+        let b = Matrix::<f64, 3, 3>::new([[1., 2., 3.], [4., 5., 6.], [0., 0., 0.]]);
+        assert_eq!(b.inverse(), None);
+    }
+
+    #[test]
+    fn test_inverse_nan() {
+        let a = Matrix::new([[f64::NAN, 2.], [3., 4.]]);
+        assert_eq!(a.inverse(), None);
+
+        let b = Matrix::new([[1., 2.], [f64::NAN, 4.]]);
+        assert_eq!(b.inverse(), None);
+    }
 }
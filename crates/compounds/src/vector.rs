@@ -1,12 +1,13 @@
 #[cfg(feature = "std")]
 use crate::bivec::BiVector;
 use crate::matrix::Matrix;
-use core::array;
 use core::ops::{Index, IndexMut};
+use core::{array, fmt};
 use numeric_static_iter::{IntoStaticIter, StaticIter};
 use numeric_traits::class::{Numeric, Real, RealSigned};
 use numeric_traits::identity::Zero;
-use numeric_traits::ops::checked::{CheckedAdd, CheckedSub};
+use numeric_traits::ops::checked::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+use numeric_traits::ops::TrigOps;
 
 pub type Vec2<T> = Vector<T, 2>;
 pub type Vec3<T> = Vector<T, 3>;
@@ -35,6 +36,47 @@ impl<T, const N: usize> Vector<T, N> {
         Vector::new(arr)
     }
 
+    /// Create a [`Vector`] with every component set to `val`. Unlike [`Vector::from_scalar`], this
+    /// can be used in const contexts.
+    pub const fn splat(val: T) -> Vector<T, N>
+    where
+        T: Copy,
+    {
+        Vector([val; N])
+    }
+
+    /// Create a [`Vector`] by calling a function with the index of each component
+    pub fn from_fn<F>(f: F) -> Vector<T, N>
+    where
+        F: FnMut(usize) -> T,
+    {
+        Vector(array::from_fn(f))
+    }
+
+    /// Apply a function to each component of this [`Vector`], producing a new [`Vector`] of the
+    /// results
+    pub fn map<U, F>(self, f: F) -> Vector<U, N>
+    where
+        F: FnMut(T) -> U,
+    {
+        Vector(self.0.into_static_iter().map(f).collect())
+    }
+
+    /// Apply a function to each pair of components from this [`Vector`] and another, producing a
+    /// new [`Vector`] of the results
+    pub fn zip_map<U, R, F>(self, other: Vector<U, N>, mut f: F) -> Vector<R, N>
+    where
+        F: FnMut(T, U) -> R,
+    {
+        let new = self
+            .0
+            .into_static_iter()
+            .zip(other.0.into_static_iter())
+            .map(|(l, r)| f(l, r))
+            .collect();
+        Vector(new)
+    }
+
     /// Convert this [`Vector`] into a single-row [`Matrix`]
     pub fn into_row(self) -> Matrix<T, 1, N> {
         Matrix::new([self.into()])
@@ -50,7 +92,7 @@ impl<T, const N: usize> Vector<T, N> {
     }
 }
 
-impl<T: Real, const N: usize> Vector<T, N> {
+impl<T: Numeric, const N: usize> Vector<T, N> {
     pub fn sum(self) -> T {
         self.0.into_static_iter().sum()
     }
@@ -64,7 +106,9 @@ impl<T: Real, const N: usize> Vector<T, N> {
             .map(|(l, r)| l * r)
             .fold(T::zero(), |acc, val| acc + val)
     }
+}
 
+impl<T: Real, const N: usize> Vector<T, N> {
     pub fn distance_squared(lhs: Vector<T, N>, rhs: Vector<T, N>) -> T {
         let two = T::one() + T::one();
 
@@ -76,6 +120,51 @@ impl<T: Real, const N: usize> Vector<T, N> {
     pub fn distance(lhs: Vector<T, N>, rhs: Vector<T, N>) -> T {
         Self::distance_squared(lhs, rhs).sqrt()
     }
+
+    /// Check whether this is a unit vector, with a magnitude within `tolerance` of one. Inexact
+    /// types will rarely produce a magnitude of exactly one, even for normalized vectors.
+    pub fn is_unit(&self, tolerance: T) -> bool {
+        let len = Self::dot_product(self.clone(), self.clone()).sqrt();
+        let diff = if len > T::one() {
+            len - T::one()
+        } else {
+            T::one() - len
+        };
+        diff <= tolerance
+    }
+
+    /// Check whether every component of this vector is within `eps` of the matching component
+    /// of another
+    pub fn approx_eq(&self, other: &Self, eps: T) -> bool {
+        Iterator::zip(self.0.iter(), other.0.iter()).all(|(l, r)| {
+            let diff = if l > r {
+                l.clone() - r.clone()
+            } else {
+                r.clone() - l.clone()
+            };
+            diff <= eps
+        })
+    }
+
+    /// Get the angle between two vectors, in radians. This will be in the range `[0, π]`.
+    pub fn angle_between(lhs: Vector<T, N>, rhs: Vector<T, N>) -> T
+    where
+        T: TrigOps,
+    {
+        let lhs_len = Self::dot_product(lhs.clone(), lhs.clone()).sqrt();
+        let rhs_len = Self::dot_product(rhs.clone(), rhs.clone()).sqrt();
+        let cos = Self::dot_product(lhs, rhs) / (lhs_len * rhs_len);
+        // Rounding may push the cosine just outside the valid range, which would produce NaN
+        let neg_one = T::zero() - T::one();
+        let cos = if cos > T::one() {
+            T::one()
+        } else if cos < neg_one {
+            neg_one
+        } else {
+            cos
+        };
+        cos.acos()
+    }
 }
 
 impl<T: RealSigned, const N: usize> Vector<T, N> {
@@ -116,6 +205,17 @@ impl<T> Vector<T, 2> {
     pub fn set_y(&mut self, val: T) {
         self.0[1] = val;
     }
+
+    /// The 2D analog of [`Vector::cross`], `x1 * y2 - y1 * x2`. This is the z component of the
+    /// cross product of the two vectors extended into 3D, and is positive when `other` is
+    /// counter-clockwise from `self`.
+    #[doc(alias = "cross")]
+    pub fn perp_dot(self, other: Self) -> T
+    where
+        T: Numeric + Clone,
+    {
+        self.x().clone() * other.y().clone() - self.y().clone() * other.x().clone()
+    }
 }
 
 impl<T> Vector<T, 3> {
@@ -166,16 +266,20 @@ impl<T> Vector<T, 3> {
 
     pub fn cross(self, other: Self) -> Vector<T, 3>
     where
-        T: Numeric + Clone,
+        T: Numeric,
     {
-        let x1 = self.y().clone() * other.z().clone();
-        let x2 = self.z().clone() * other.y().clone();
+        // Each component is used twice, so only clone for the first use
+        let [ax, ay, az] = self.0;
+        let [bx, by, bz] = other.0;
 
-        let y1 = self.z().clone() * other.x().clone();
-        let y2 = self.x().clone() * other.z().clone();
+        let x1 = ay.clone() * bz.clone();
+        let x2 = az.clone() * by.clone();
 
-        let z1 = self.x().clone() * other.y().clone();
-        let z2 = self.y().clone() * other.x().clone();
+        let y1 = az * bx.clone();
+        let y2 = ax.clone() * bz;
+
+        let z1 = ax * by;
+        let z2 = ay * bx;
 
         Vector::new([x1 - x2, y1 - y2, z1 - z2])
     }
@@ -253,6 +357,21 @@ impl<T: Zero, const N: usize> Zero for Vector<T, N> {
     }
 }
 
+/// Displays the vector as a tuple of its components, such as `(1, 2, 3)`. Formatting options are
+/// applied to every component.
+impl<T: fmt::Display, const N: usize> fmt::Display for Vector<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (idx, val) in self.0.iter().enumerate() {
+            if idx != 0 {
+                write!(f, ", ")?;
+            }
+            fmt::Display::fmt(val, f)?;
+        }
+        write!(f, ")")
+    }
+}
+
 impl<T: Default, const N: usize> Default for Vector<T, N> {
     fn default() -> Self {
         Vector(array::from_fn(|_| T::default()))
@@ -345,6 +464,7 @@ ops_impl!(Add, add, +);
 ops_impl!(Sub, sub, -);
 ops_impl!(Mul, mul, *);
 ops_impl!(Div, div, /);
+ops_impl!(Rem, rem, %);
 
 macro_rules! assign_ops_impl {
     ($trait:ident, $meth:ident, $op:tt) => {
@@ -435,3 +555,237 @@ where
         Some(Vector(new))
     }
 }
+
+impl<T, const N: usize> CheckedMul for Vector<T, N>
+where
+    T: CheckedMul,
+{
+    type Output = Vector<T::Output, N>;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self::Output> {
+        let new = self
+            .0
+            .into_static_iter()
+            .zip(rhs.0.into_static_iter())
+            .map(|(l, r)| l.checked_mul(r))
+            .collect::<Option<_>>()?;
+        Some(Vector(new))
+    }
+}
+
+impl<T, const N: usize> CheckedDiv for Vector<T, N>
+where
+    T: CheckedDiv,
+{
+    type Output = Vector<T::Output, N>;
+
+    fn checked_div(self, rhs: Self) -> Option<Self::Output> {
+        let new = self
+            .0
+            .into_static_iter()
+            .zip(rhs.0.into_static_iter())
+            .map(|(l, r)| l.checked_div(r))
+            .collect::<Option<_>>()?;
+        Some(Vector(new))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use numeric_ints::{BigInt, U};
+
+    #[test]
+    fn test_big_int() {
+        let big = |val: i64| BigInt::from(val);
+        let a = Vector::new([big(1), big(-2), BigInt::from(u64::MAX)]);
+        let b = Vector::new([big(4), big(5), big(-6)]);
+        let max = BigInt::from(u64::MAX);
+
+        assert_eq!(a.clone().sum(), &max - &big(1));
+        assert_eq!(
+            Vector::dot_product(a.clone(), b.clone()),
+            &(&max * &big(-6)) - &big(6)
+        );
+        assert_eq!(
+            a.cross(b),
+            Vector::new([
+                &big(12) - &(&max * &big(5)),
+                &(&max * &big(4)) + &big(6),
+                big(13),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_is_zero() {
+        assert!(Vector::<i32, 3>::zero().is_zero());
+        assert!(!Vector::new([0, 0, 1]).is_zero());
+
+        // `is_zero` should stop at the first nonzero component
+        #[derive(Debug)]
+        struct Tripwire(u8);
+
+        impl Zero for Tripwire {
+            fn zero() -> Self {
+                Tripwire(0)
+            }
+
+            fn is_zero(&self) -> bool {
+                assert_ne!(self.0, 2, "component checked after a nonzero one");
+                self.0 == 0
+            }
+        }
+
+        assert!(!Vector::new([Tripwire(0), Tripwire(1), Tripwire(2)]).is_zero());
+    }
+
+    #[test]
+    fn test_is_unit() {
+        assert!(Vector::new([1.0, 0.0, 0.0]).is_unit(0.0));
+        assert!(Vector::new([0.6, 0.8]).is_unit(1e-6));
+        let third = 1.0f64 / 3.0f64.sqrt();
+        assert!(Vector::new([third, third, third]).is_unit(1e-9));
+        assert!(Vector::new([0.6, 0.8001]).is_unit(1e-3));
+
+        assert!(!Vector::new([0.6, 0.8001]).is_unit(1e-6));
+        assert!(!Vector::new([1.0, 1.0]).is_unit(1e-6));
+        assert!(!Vector::<f64, 2>::zero().is_unit(1e-6));
+    }
+
+    #[test]
+    fn test_rem() {
+        assert_eq!(
+            Vector::from([7, 8]) % Vector::from([3, 3]),
+            Vector::from([1, 2])
+        );
+        assert_eq!(Vector::from([7, 8, 9]) % 4, Vector::from([3, 0, 1]));
+
+        let mut v = Vector::from([7, 8]);
+        v %= Vector::from([3, 3]);
+        assert_eq!(v, Vector::from([1, 2]));
+    }
+
+    #[test]
+    fn test_checked_mul() {
+        assert_eq!(
+            Vector::from([2u8, 3]).checked_mul(Vector::from([4, 5])),
+            Some(Vector::from([8, 15]))
+        );
+        assert_eq!(
+            Vector::from([2u8, 128]).checked_mul(Vector::from([4, 2])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_checked_div() {
+        assert_eq!(
+            Vector::from([8u8, 15]).checked_div(Vector::from([4, 5])),
+            Some(Vector::from([2, 3]))
+        );
+        assert_eq!(
+            Vector::from([8u8, 15]).checked_div(Vector::from([4, 0])),
+            None
+        );
+        assert_eq!(
+            Vector::from([8i8, i8::MIN]).checked_div(Vector::from([4, -1])),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_fn() {
+        assert_eq!(
+            Vector::<i32, 4>::from_fn(|i| i as i32),
+            Vector::new([0, 1, 2, 3])
+        );
+        assert_eq!(
+            Vector::<i32, 3>::from_fn(|i| i32::from(i == 1)),
+            Vector::new([0, 1, 0])
+        );
+    }
+
+    #[test]
+    fn test_splat() {
+        const ONES: Vector<i32, 3> = Vector::splat(1);
+        assert_eq!(ONES, Vector::new([1, 1, 1]));
+        assert_eq!(ONES, Vector::from_scalar(1));
+    }
+
+    #[test]
+    fn test_map() {
+        let a = Vector::new([1, 2, 3]);
+        assert_eq!(a.map(|v| v * 2), Vector::new([2, 4, 6]));
+        assert_eq!(a.map(|v| v as f32 / 2.0), Vector::new([0.5, 1.0, 1.5]));
+    }
+
+    #[test]
+    fn test_display() {
+        extern crate alloc;
+        use alloc::format;
+
+        assert_eq!(format!("{}", Vector::new([1, 2, 3])), "(1, 2, 3)");
+        assert_eq!(format!("{:.2}", Vector::new([0.5, -1.0])), "(0.50, -1.00)");
+        assert_eq!(format!("{}", Vector::<i32, 0>::new([])), "()");
+    }
+
+    #[test]
+    fn test_zip_map() {
+        let a = Vector::new([1, 5, 3, 8]);
+        let b = Vector::new([4, 2, 3, 7]);
+        assert_eq!(a.zip_map(b, i32::min), Vector::new([1, 2, 3, 7]));
+    }
+
+    #[test]
+    fn test_integer_ops() {
+        let a = Vector::new([1, 2, 3]);
+        let b = Vector::new([4, -5, 6]);
+        assert_eq!(a.sum(), 6);
+        assert_eq!(b.product(), -120);
+        assert_eq!(Vector::dot_product(a, b), 12);
+
+        let c = Vector::new([U::<4>::from_u32(1), U::from_u32(2), U::from_u32(3)]);
+        let d = Vector::new([U::<4>::from_u32(4), U::from_u32(5), U::from_u32(6)]);
+        assert_eq!(Vector::dot_product(c, d), U::from_u32(32));
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        let a = Vector::new([1.0f64, 2.0, 3.0]);
+        let b = Vector::new([1.0 + 1e-10, 2.0 - 1e-10, 3.0]);
+        let c = Vector::new([1.0, 2.5, 3.0]);
+        assert!(a.approx_eq(&a, 0.0));
+        assert!(a.approx_eq(&b, 1e-9));
+        assert!(b.approx_eq(&a, 1e-9));
+        assert!(!a.approx_eq(&b, 1e-11));
+        assert!(!a.approx_eq(&c, 1e-9));
+    }
+
+    #[test]
+    fn test_perp_dot() {
+        let x = Vector::new([1, 0]);
+        let y = Vector::new([0, 1]);
+        assert_eq!(x.perp_dot(y), 1);
+        assert_eq!(y.perp_dot(x), -1);
+        assert_eq!(x.perp_dot(x), 0);
+        assert_eq!(Vector::new([2, 3]).perp_dot(Vector::new([4, 5])), -2);
+
+        let a = Vector::new([2, 3]);
+        let b = Vector::new([4, 5]);
+        let cross = Vector::new([2, 3, 0]).cross(Vector::new([4, 5, 0]));
+        assert_eq!(*cross.z(), a.perp_dot(b));
+    }
+
+    #[test]
+    fn test_angle_between() {
+        use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+        let x = Vector::new([1.0f64, 0.0]);
+        let y = Vector::new([0.0f64, 1.0]);
+        assert!((Vector::angle_between(x, y) - FRAC_PI_2).abs() < 1e-12);
+        assert!((Vector::angle_between(x, x * 3.0) - 0.0).abs() < 1e-12);
+        assert!((Vector::angle_between(x, x * -1.0) - PI).abs() < 1e-12);
+        assert!((Vector::angle_between(x, x + y) - FRAC_PI_4).abs() < 1e-12);
+    }
+}
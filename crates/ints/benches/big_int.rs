@@ -71,6 +71,34 @@ pub fn bench_add(c: &mut Criterion) {
         );
 }
 
+pub fn bench_usize_ops(c: &mut Criterion) {
+    let big = BigInt::from(usize::MAX) * BigInt::from(usize::MAX);
+
+    c.benchmark_group("BigInt::add_usize")
+        .bench_with_input(
+            BenchmarkId::new("add_usize", "usize::MAX^2, 1"),
+            &big,
+            |b, big| b.iter(|| black_box(big).add_usize(black_box(1))),
+        )
+        .bench_with_input(
+            BenchmarkId::new("add", "usize::MAX^2, 1"),
+            &big,
+            |b, big| b.iter(|| black_box(big) + BigInt::from(black_box(1usize))),
+        );
+
+    c.benchmark_group("BigInt::mul_usize")
+        .bench_with_input(
+            BenchmarkId::new("mul_usize", "usize::MAX^2, 10"),
+            &big,
+            |b, big| b.iter(|| black_box(big).mul_usize(black_box(10))),
+        )
+        .bench_with_input(
+            BenchmarkId::new("mul", "usize::MAX^2, 10"),
+            &big,
+            |b, big| b.iter(|| black_box(big) * BigInt::from(black_box(10usize))),
+        );
+}
+
 pub fn bench_sub(c: &mut Criterion) {
     let one = BigInt::from(1);
     let max = BigInt::from(usize::MAX);
@@ -141,6 +169,6 @@ pub fn bench_shl(c: &mut Criterion) {
 criterion_group!(
     name = benches;
     config = make_criterion();
-    targets = bench_from, bench_clone, bench_add, bench_sub, bench_mul, bench_div, bench_shl
+    targets = bench_from, bench_clone, bench_add, bench_usize_ops, bench_sub, bench_mul, bench_div, bench_shl
 );
 criterion_main!(benches);
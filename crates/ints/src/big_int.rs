@@ -2,7 +2,9 @@
 //!
 //! Small values are stored inline, large values are stored in a refcounted interner.
 
+use crate::{I, U};
 use alloc::boxed::Box;
+use alloc::vec;
 use alloc::vec::Vec;
 use core::borrow::Borrow;
 use core::cmp::Ordering;
@@ -10,20 +12,33 @@ use core::fmt::{Binary, Debug, Display, LowerHex, UpperHex, Write};
 use core::hint::unreachable_unchecked;
 use core::{fmt, mem, num, ops};
 use numeric_bits::algos::{
-    BitwiseDiv, ElementAdd, ElementBitand, ElementBitor, ElementBitxor, ElementMul, ElementNot,
+    ElementAdd, ElementBitand, ElementBitor, ElementBitxor, ElementDiv, ElementMul, ElementNot,
     ElementShl, ElementShr, ElementSub,
 };
 use numeric_bits::bit_slice::BitSliceExt;
 use numeric_bits::utils::*;
 use numeric_traits::cast::{FromChecked, FromStrRadix};
-use numeric_traits::class::{Integral, Numeric, Signed};
+use numeric_traits::class::{Integral, MaybeBounded, Numeric, Signed};
 use numeric_traits::identity::{One, Zero};
-use numeric_traits::ops::Pow;
+use numeric_traits::ops::checked::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedShl, CheckedSub,
+};
+use numeric_traits::ops::widening::WideningMul;
+use numeric_traits::ops::{Gcd, Lcm, Pow};
 use numeric_utils::intern::InternId;
 use numeric_utils::{static_assert, static_assert_traits, Interner};
 
 #[macro_use]
 mod macros;
+#[cfg(feature = "rand")]
+mod rand_impl;
+mod scoped;
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+#[cfg(feature = "rand")]
+pub use rand_impl::UniformBigInt;
+pub use scoped::{BigIntIn, BigIntStore};
 
 static INT_STORE: Interner<Box<[usize]>> = Interner::new();
 
@@ -136,6 +151,17 @@ impl MaybeInline<'_> {
     }
 }
 
+/// The sign of a [`BigInt`], as returned by [`BigInt::sign`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Sign {
+    /// The value is less than zero
+    Negative,
+    /// The value is exactly zero
+    Zero,
+    /// The value is greater than zero
+    Positive,
+}
+
 /// A 'big' integer - an unbounded signed value, capable of representing any value up to however
 /// many bytes the running computer can reasonably hold in memory.
 pub struct BigInt(TaggedOffset);
@@ -200,6 +226,446 @@ impl BigInt {
         }
     }
 
+    /// Create a new `BigInt` from a slice of `usize` limbs and a sign. Limbs are in little-endian
+    /// order, the first limb being the least significant. Trailing zero limbs are ignored, and
+    /// an empty slice is treated as zero.
+    ///
+    /// Zero is never negative - a zero magnitude will produce a positive value no matter the
+    /// provided sign.
+    #[must_use]
+    pub fn from_limbs(limbs: &[usize], negative: bool) -> BigInt {
+        if limbs.is_empty() {
+            BigInt::new()
+        } else {
+            BigInt::new_slice(limbs, negative)
+        }
+    }
+
+    /// Create `2^k`, the value with only bit `k` set, equivalent to `1 << k`. This builds the limbs
+    /// directly, rather than going through a shift.
+    #[must_use]
+    pub fn one_shl(k: usize) -> BigInt {
+        let bits = usize::BITS as usize;
+        let mut limbs = vec![0; k / bits + 1];
+        limbs[k / bits] = 1 << (k % bits);
+        BigInt::new_slice(limbs, false)
+    }
+
+    /// Parse a value from a string, detecting the radix from a `0x`, `0o`, or `0b` prefix for
+    /// hexadecimal, octal, or binary. Strings without a prefix are parsed as decimal. The prefix
+    /// may be preceded by a `-` or `+` sign, as in `-0x10`.
+    ///
+    /// # Errors
+    ///
+    /// If there are no digits after the sign and prefix, or they aren't valid for the detected
+    /// radix
+    pub fn from_str_prefixed(str: &str) -> Result<BigInt, FromStrError> {
+        let (neg, rest) = match str.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, str.strip_prefix('+').unwrap_or(str)),
+        };
+        let (radix, digits) = match rest.get(..2) {
+            Some("0x" | "0X") => (16, &rest[2..]),
+            Some("0o" | "0O") => (8, &rest[2..]),
+            Some("0b" | "0B") => (2, &rest[2..]),
+            _ => (10, rest),
+        };
+        let out = parse_digits(digits, str.len() - digits.len(), radix)?;
+        Ok(if neg { -out } else { out })
+    }
+
+    /// Build a value from a slice of digit values in the given radix, most significant digit
+    /// first. This is like [`FromStrRadix::from_str_radix`], for input which has already been
+    /// converted from characters. An empty slice produces zero.
+    ///
+    /// # Errors
+    ///
+    /// If `radix` is less than 2, or any digit isn't less than `radix`
+    pub fn from_digits(digits: &[u8], radix: u32) -> Result<BigInt, FromStrError> {
+        if radix < 2 {
+            return Err(FromStrError::InvalidRadix(radix));
+        }
+        let mut limbs = vec![0];
+        for (idx, &digit) in digits.iter().enumerate() {
+            if u32::from(digit) >= radix {
+                return Err(FromStrError::InvalidDigit(digit, idx));
+            }
+            mul_add_limbs(&mut limbs, radix as usize, usize::from(digit));
+        }
+        Ok(BigInt::new_slice(limbs, false))
+    }
+
+    /// Call a function with the magnitude of this value, as a slice of `usize` limbs. Limbs are
+    /// in little-endian order, the first limb being the least significant, and the slice will
+    /// never contain trailing zero limbs except when the value is zero, where it will be `[0]`.
+    ///
+    /// The sign of the value can be retrieved through [`Signed::is_negative`].
+    pub fn with_limbs<R>(&self, f: impl FnOnce(&[usize]) -> R) -> R {
+        self.with_slice(f)
+    }
+
+    /// Get the sign of this value. Unlike [`Signed::is_positive`], this distinguishes zero from
+    /// positive values.
+    #[must_use]
+    pub fn sign(&self) -> Sign {
+        if self.is_zero() {
+            Sign::Zero
+        } else if self.is_negative() {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        }
+    }
+
+    /// Get the number of trailing zero bits in the magnitude of this value, the largest `k` such
+    /// that `2^k` evenly divides it. The sign is ignored.
+    ///
+    /// Zero has no set bits to stop at, and returns `0`.
+    #[must_use]
+    pub fn trailing_zeros(&self) -> u64 {
+        self.with_slice(|slice| {
+            slice.iter().position(|&limb| limb != 0).map_or(0, |idx| {
+                (idx as u64 * u64::from(usize::BITS)) + u64::from(slice[idx].trailing_zeros())
+            })
+        })
+    }
+
+    /// Divide this value by `rhs`, returning both the quotient and remainder from a single
+    /// division. Like the primitive integers, the quotient is truncated towards zero, and the
+    /// remainder takes the sign of `self`.
+    #[must_use]
+    pub fn div_rem(&self, rhs: &BigInt) -> (BigInt, BigInt) {
+        let ((quot, quot_neg), (rem, rem_neg)) = BigInt::with_slices(self, rhs, |this, other| {
+            div_limbs(this, self.is_negative(), other, rhs.is_negative())
+        });
+        (
+            BigInt::new_slice(quot, quot_neg),
+            BigInt::new_slice(rem, rem_neg),
+        )
+    }
+
+    /// Add a `usize` to this value. This works directly on the limbs of this value in a single
+    /// carry pass, rather than converting `rhs` into a `BigInt` and taking the general path.
+    #[must_use]
+    pub fn add_usize(&self, rhs: usize) -> BigInt {
+        let (out, neg) =
+            self.with_slice(|this| add_usize_limbs(this, self.is_negative(), rhs, false));
+        BigInt::new_slice(out, neg)
+    }
+
+    /// Subtract a `usize` from this value. Like [`BigInt::add_usize`], this avoids converting
+    /// `rhs` into a `BigInt`.
+    #[must_use]
+    pub fn sub_usize(&self, rhs: usize) -> BigInt {
+        let (out, neg) =
+            self.with_slice(|this| add_usize_limbs(this, self.is_negative(), rhs, true));
+        BigInt::new_slice(out, neg)
+    }
+
+    /// Multiply this value by a `usize`. Like [`BigInt::add_usize`], this avoids converting
+    /// `rhs` into a `BigInt`, multiplying each limb in a single pass.
+    #[must_use]
+    pub fn mul_usize(&self, rhs: usize) -> BigInt {
+        let out = self.with_slice(|this| {
+            let mut carry = 0;
+            let mut out = this
+                .iter()
+                .map(|&limb| {
+                    let (low, high) = WideningMul::widening_mul(limb, rhs, carry);
+                    carry = high;
+                    low
+                })
+                .collect::<Vec<_>>();
+            out.push(carry);
+            out
+        });
+        BigInt::new_slice(out, self.is_negative())
+    }
+
+    /// Calculate `self * b + c`. The product is never stored as a `BigInt`, saving interning a
+    /// large intermediate value.
+    #[must_use]
+    pub fn mul_add(&self, b: &BigInt, c: &BigInt) -> BigInt {
+        let prod = BigInt::with_slices(self, b, ElementMul::mul);
+        let prod_neg = self.is_negative() != b.is_negative();
+        let (out, neg) =
+            c.with_slice(|c_limbs| add_limbs(&prod, prod_neg, c_limbs, c.is_negative()));
+        BigInt::new_slice(out, neg)
+    }
+
+    /// Get the absolute difference between this value and another, `|self - other|`. This works
+    /// on the magnitudes directly, without building the signed difference first.
+    #[must_use]
+    pub fn abs_diff(&self, other: &BigInt) -> BigInt {
+        let same_sign = self.is_negative() == other.is_negative();
+        let out = BigInt::with_slices(self, other, |this, other| {
+            if same_sign {
+                ElementSub::sub(this, other).0
+            } else {
+                ElementAdd::add(this, other)
+            }
+        });
+        BigInt::new_slice(out, false)
+    }
+
+    /// Calculate the greatest common divisor of this value and `other`, along with Bézout
+    /// coefficients `x` and `y` such that `self * x + other * y == g`. Returns `(g, x, y)`, where
+    /// `g` is never negative.
+    #[must_use]
+    pub fn gcd_extended(&self, other: &BigInt) -> (BigInt, BigInt, BigInt) {
+        // Extended Euclidean algorithm - each remainder is kept as a combination of the inputs,
+        // `r == self * s + other * t`
+        let (mut old_r, mut r) = (self.clone(), other.clone());
+        let (mut old_s, mut s) = (BigInt::one(), BigInt::zero());
+        let (mut old_t, mut t) = (BigInt::zero(), BigInt::one());
+
+        while !r.is_zero() {
+            let (quot, rem) = old_r.div_rem(&r);
+            old_r = mem::replace(&mut r, rem);
+            let new_s = &old_s - &quot * &s;
+            old_s = mem::replace(&mut s, new_s);
+            let new_t = &old_t - &quot * &t;
+            old_t = mem::replace(&mut t, new_t);
+        }
+
+        if old_r.is_negative() {
+            (-old_r, -old_s, -old_t)
+        } else {
+            (old_r, old_s, old_t)
+        }
+    }
+
+    /// Find the inverse of this value modulo `modulus`, the value `x` in `[0, modulus)` such that
+    /// `self * x % modulus == 1`. Returns `None` if `self` and `modulus` aren't coprime, and so
+    /// no inverse exists.
+    ///
+    /// # Panics
+    ///
+    /// If `modulus` is not positive
+    #[must_use]
+    pub fn modinv(&self, modulus: &BigInt) -> Option<BigInt> {
+        assert!(*modulus > 0, "Modulus must be positive");
+
+        let (gcd, x, _) = self.gcd_extended(modulus);
+        if !gcd.is_one() {
+            return None;
+        }
+
+        let out = x % modulus;
+        Some(if out.is_negative() {
+            out + modulus
+        } else {
+            out
+        })
+    }
+
+    /// Raise this value to the power `exp`, modulo `modulus`, without ever holding an intermediate
+    /// larger than `modulus` squared. The result is always in the range `[0, modulus)`.
+    ///
+    /// # Panics
+    ///
+    /// If `exp` is negative, or `modulus` is not positive
+    #[must_use]
+    pub fn modpow(&self, exp: &BigInt, modulus: &BigInt) -> BigInt {
+        assert!(!exp.is_negative(), "Exponent must be non-negative");
+        assert!(*modulus > 0, "Modulus must be positive");
+
+        let mut base = self % modulus;
+        if base.is_negative() {
+            base += modulus;
+        }
+        let mut out = BigInt::one() % modulus;
+
+        exp.with_slice(|limbs| {
+            let bits = limbs.bit_len() - limbs[limbs.len() - 1].leading_zeros() as usize;
+            for idx in 0..bits {
+                if limbs.get_bit(idx) {
+                    out = &out * &base % modulus;
+                }
+                if idx + 1 < bits {
+                    base = &base * &base % modulus;
+                }
+            }
+        });
+
+        out
+    }
+
+    /// Raise this value to a power, unless the result could need more than `max_bits` bits. The
+    /// size of the result is estimated before doing any work, so this is safe to call with
+    /// untrusted inputs which might otherwise try to allocate huge amounts of memory.
+    ///
+    /// The estimate is an upper bound, so this may return `None` for results up to `exp - 1` bits
+    /// smaller than `max_bits`.
+    #[must_use]
+    pub fn try_pow(&self, exp: u32, max_bits: usize) -> Option<BigInt> {
+        // Zero and one never grow, no matter the exponent
+        let bits = self.magnitude_bits();
+        if bits > 1 {
+            let estimate = bits.checked_mul(u64::from(exp))?;
+            if usize::try_from(estimate).map_or(true, |estimate| estimate > max_bits) {
+                return None;
+            }
+        }
+
+        let mut out = BigInt::one();
+        let mut base = self.clone();
+        let mut exp = exp;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                out *= &base;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        Some(out)
+    }
+
+    /// Calculate the integer square root of this value along with the remainder, returning
+    /// `(r, self - r * r)` where `r` is the largest value with `r * r <= self`.
+    ///
+    /// # Panics
+    ///
+    /// If this value is negative
+    #[must_use]
+    pub fn sqrt_rem(&self) -> (BigInt, BigInt) {
+        assert!(
+            !self.is_negative(),
+            "Can't take the square root of a negative number"
+        );
+        if self.is_zero() {
+            return (BigInt::zero(), BigInt::zero());
+        }
+
+        // Newton's method, starting from a power of two no smaller than the root. Each step
+        // decreases the estimate until it reaches the floor of the root.
+        let bits = usize::try_from(self.magnitude_bits().div_ceil(2)).unwrap();
+        let mut root = BigInt::one_shl(bits);
+        loop {
+            let next = (&root + &(self / &root)) >> 1usize;
+            if next >= root {
+                break;
+            }
+            root = next;
+        }
+
+        let rem = self - &(&root * &root);
+        (root, rem)
+    }
+
+    /// Find a square root of this value modulo the odd prime `p`, a value `r` in `[0, p)` such
+    /// that `r * r % p == self % p`, using the Tonelli-Shanks algorithm. Returns `None` if this
+    /// value is a quadratic non-residue, and so has no square root. The other root is `p - r`.
+    ///
+    /// If `p` isn't actually prime, the result is meaningless and this may loop forever.
+    ///
+    /// # Panics
+    ///
+    /// If `p` is not an odd value greater than two
+    #[must_use]
+    pub fn sqrt_mod(&self, p: &BigInt) -> Option<BigInt> {
+        assert!(
+            *p > 2 && p.trailing_zeros() == 0,
+            "Modulus must be an odd prime"
+        );
+
+        let mut a = self % p;
+        if a.is_negative() {
+            a += p;
+        }
+        if a.is_zero() {
+            return Some(a);
+        }
+
+        // Euler's criterion - residues have a^((p - 1) / 2) == 1
+        let minus_one = p - BigInt::one();
+        let half = &minus_one >> BigInt::one();
+        if !a.modpow(&half, p).is_one() {
+            return None;
+        }
+
+        // Write p - 1 as q * 2^s, with q odd
+        let s = minus_one.trailing_zeros();
+        let q = &minus_one >> BigInt::from(s);
+
+        // Any non-residue works - roughly half of all values are, so this finds one quickly
+        let mut z = BigInt::from(2);
+        while z.modpow(&half, p) != minus_one {
+            z += BigInt::one();
+        }
+
+        let mut m = s;
+        let mut c = z.modpow(&q, p);
+        let mut t = a.modpow(&q, p);
+        let mut r = a.modpow(&((&q + BigInt::one()) >> BigInt::one()), p);
+
+        while !t.is_one() {
+            // Find the least i such that t^(2^i) == 1. Since a is a residue, i < m.
+            let mut i = 0;
+            let mut t2 = t.clone();
+            while !t2.is_one() {
+                t2 = &t2 * &t2 % p;
+                i += 1;
+            }
+
+            let b = c.modpow(&(BigInt::one() << BigInt::from(m - i - 1)), p);
+            m = i;
+            c = &b * &b % p;
+            t = &t * &c % p;
+            r = &r * &b % p;
+        }
+
+        Some(r)
+    }
+
+    /// Test whether this value is prime using the Miller-Rabin primality test, with `rounds`
+    /// different witnesses. A return of `false` means the value is definitely composite, while
+    /// `true` means it is prime with a probability of error at most `4^-rounds`.
+    ///
+    /// Witnesses are chosen deterministically, starting with the first 12 primes. With at least 12
+    /// rounds, the result is exact for all values below `3.3 * 10^24`.
+    #[must_use]
+    pub fn is_probably_prime(&self, rounds: usize) -> bool {
+        const WITNESSES: [u8; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+        if *self < 2 {
+            return false;
+        } else if *self < 4 {
+            return true;
+        } else if self.trailing_zeros() > 0 {
+            return false;
+        }
+
+        let minus_one = self - BigInt::one();
+        let twos = minus_one.trailing_zeros();
+        let odd = &minus_one >> BigInt::from(twos);
+
+        let witnesses = WITNESSES
+            .iter()
+            .map(|&w| BigInt::from(w))
+            .chain((38u64..).map(BigInt::from))
+            .take_while(|w| *w < minus_one)
+            .take(rounds);
+
+        'witness: for witness in witnesses {
+            let mut x = witness.modpow(&odd, self);
+            if x.is_one() || x == minus_one {
+                continue;
+            }
+            for _ in 1..twos {
+                x = &x * &x % self;
+                if x == minus_one {
+                    continue 'witness;
+                }
+            }
+            return false;
+        }
+
+        true
+    }
+
     #[inline]
     fn with_slice<R>(&self, f: impl FnOnce(&[usize]) -> R) -> R {
         f(self.val().slice())
@@ -209,7 +675,7 @@ impl BigInt {
         // This is the simplest way - mod base for digit, div base for next digit
         // It isn't super fast though, so there are probably optimization improvements
         let mut digits = Vec::new();
-        let mut scratch = self.clone();
+        let mut scratch = self.clone().abs();
 
         while scratch > 0 {
             let digit = u8::from_checked(scratch.clone() % base)
@@ -235,6 +701,46 @@ impl BigInt {
         self.0.tag().inline()
     }
 
+    /// Calculate `n!`, the product of all integers from `1` to `n`. `0!` is `1`.
+    #[must_use]
+    pub fn factorial(n: u64) -> BigInt {
+        if n < 2 {
+            BigInt::one()
+        } else {
+            BigInt::product_range(2, n)
+        }
+    }
+
+    /// Calculate the binomial coefficient `n choose k`, the number of ways to pick `k` items from
+    /// a set of `n`. Returns zero if `k > n`.
+    #[must_use]
+    pub fn binomial(n: u64, k: u64) -> BigInt {
+        if k > n {
+            return BigInt::zero();
+        }
+
+        let k = u64::min(k, n - k);
+        let mut out = BigInt::one();
+        // After each step `out` is `(n - k + i) choose i`, so the division is always exact
+        for i in 1..=k {
+            out = out * (n - k + i) / i;
+        }
+        out
+    }
+
+    /// Multiply all integers in `[low, high]`, splitting the range in half so that both sides of
+    /// each multiplication stay a similar size.
+    fn product_range(low: u64, high: u64) -> BigInt {
+        match high - low {
+            0 => BigInt::from(low),
+            1 => BigInt::from(low) * high,
+            _ => {
+                let mid = low + (high - low) / 2;
+                BigInt::product_range(low, mid) * BigInt::product_range(mid + 1, high)
+            }
+        }
+    }
+
     /// Check whether this value is stored in the global interner
     #[must_use]
     #[inline]
@@ -242,6 +748,81 @@ impl BigInt {
         !self.0.tag().inline()
     }
 
+    /// Create a value from a magnitude as little-endian bytes, and a sign
+    fn from_le_bytes(bytes: &[u8], negative: bool) -> BigInt {
+        let limbs = bytes
+            .chunks(mem::size_of::<usize>())
+            .map(|chunk| {
+                let mut limb = [0; mem::size_of::<usize>()];
+                limb[..chunk.len()].copy_from_slice(chunk);
+                usize::from_le_bytes(limb)
+            })
+            .collect::<Vec<_>>();
+        BigInt::from_limbs(&limbs, negative)
+    }
+
+    /// Get the magnitude of this value as little-endian bytes
+    fn magnitude_bytes(&self) -> Vec<u8> {
+        self.with_slice(|limbs| limbs.iter().flat_map(|limb| limb.to_le_bytes()).collect())
+    }
+
+    /// Get the number of bits needed to represent the magnitude of this value, zero for zero.
+    fn magnitude_bits(&self) -> u64 {
+        self.with_slice(|limbs| {
+            let top = limbs[limbs.len() - 1];
+            (limbs.len() as u64 * u64::from(usize::BITS)) - u64::from(top.leading_zeros())
+        })
+    }
+
+    /// Get `2^exp` as a float, for `exp` within the normal exponent range of `f64`.
+    fn f64_pow2(exp: u64) -> f64 {
+        debug_assert!(exp <= 1023);
+        f64::from_bits((1023 + exp) << (f64::MANTISSA_DIGITS - 1))
+    }
+
+    /// Convert this value to a float, if it can be represented exactly. Returns `None` if the
+    /// value has more significant bits than fit in the mantissa of an `f64`, or is too large for
+    /// its exponent.
+    #[must_use]
+    pub fn to_f64_exact(&self) -> Option<f64> {
+        let bits = self.magnitude_bits();
+        let zeros = self.trailing_zeros();
+        if bits > 1024 || bits - zeros > u64::from(f64::MANTISSA_DIGITS) {
+            return None;
+        }
+
+        let mantissa = u64::from_checked(self.clone().abs() >> BigInt::from(zeros))?;
+        // Both the mantissa and the scale are exact, so their product is as well
+        let out = mantissa as f64 * Self::f64_pow2(zeros);
+        Some(if self.is_negative() { -out } else { out })
+    }
+
+    /// Convert this value to the nearest float, rounding ties to even. Values too large for an
+    /// `f64` become [`f64::INFINITY`] or [`f64::NEG_INFINITY`].
+    #[must_use]
+    pub fn to_f64_saturating(&self) -> f64 {
+        let bits = self.magnitude_bits();
+        let out = if bits <= 64 {
+            u64::from_checked(self.clone().abs()).unwrap() as f64
+        } else if bits > 1024 {
+            f64::INFINITY
+        } else {
+            // Keep the top 64 bits, and fold everything below them into a sticky low bit. A `u64`
+            // has enough spare bits past the mantissa that rounding this gives the same result as
+            // rounding the full value.
+            let shift = bits - 64;
+            let top = u64::from_checked(self.clone().abs() >> BigInt::from(shift)).unwrap();
+            let sticky = u64::from(self.trailing_zeros() < shift);
+            // May overflow to infinity once rounded, which is what we want
+            (top | sticky) as f64 * Self::f64_pow2(shift)
+        };
+        if self.is_negative() {
+            -out
+        } else {
+            out
+        }
+    }
+
     /// Generate an approximation of this value as a float
     ///
     /// If the value is large, this may return [`f64::INFINITY`] or [`f64::NEG_INFINITY`].
@@ -380,8 +961,10 @@ impl Ord for BigInt {
             if this.len() != other.len() {
                 usize::cmp(&this.len(), &other.len())
             } else {
+                // Most significant limb first
                 this.iter()
-                    .zip(other.iter())
+                    .rev()
+                    .zip(other.iter().rev())
                     .find_map(|(l, r)| match l.cmp(r) {
                         Ordering::Equal => None,
                         other => Some(other),
@@ -398,7 +981,7 @@ impl Ord for BigInt {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 enum Side {
     Above,
     Below,
@@ -406,7 +989,7 @@ enum Side {
 
 /// The error for when you try to convert a `BigInt` with a value that is too large or small for
 /// the type being converted into.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OutOfRangeError(Side);
 
 impl OutOfRangeError {
@@ -419,6 +1002,145 @@ impl OutOfRangeError {
     }
 }
 
+/// The error for when you try to convert a float into a `BigInt`, and the float isn't finite
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromFloatError {
+    /// The float was `NaN`
+    Nan,
+    /// The float was positive or negative infinity
+    Infinite,
+}
+
+/// Converts exactly, truncating any fractional part towards zero
+impl TryFrom<f64> for BigInt {
+    type Error = FromFloatError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        const MANTISSA_BITS: u32 = f64::MANTISSA_DIGITS - 1;
+        const EXP_BIAS: i64 = 1023 + MANTISSA_BITS as i64;
+
+        if value.is_nan() {
+            return Err(FromFloatError::Nan);
+        } else if value.is_infinite() {
+            return Err(FromFloatError::Infinite);
+        }
+
+        let bits = value.to_bits();
+        let exp = ((bits >> MANTISSA_BITS) & 0x7FF) as i64;
+        // Zero and subnormals are always less than one
+        if exp == 0 {
+            return Ok(BigInt::zero());
+        }
+        let mantissa = (bits & ((1 << MANTISSA_BITS) - 1)) | (1 << MANTISSA_BITS);
+
+        let shift = exp - EXP_BIAS;
+        let out = if shift >= 0 {
+            BigInt::from(mantissa) << BigInt::from(shift)
+        } else {
+            BigInt::from(
+                mantissa
+                    .checked_shr(shift.unsigned_abs() as u32)
+                    .unwrap_or(0),
+            )
+        };
+
+        Ok(if value.is_sign_negative() { -out } else { out })
+    }
+}
+
+/// Converts exactly, truncating any fractional part towards zero
+impl TryFrom<f32> for BigInt {
+    type Error = FromFloatError;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        BigInt::try_from(f64::from(value))
+    }
+}
+
+impl<const N: usize> From<U<N>> for BigInt {
+    fn from(val: U<N>) -> Self {
+        BigInt::from_le_bytes(&val.to_le_bytes(), false)
+    }
+}
+
+impl<const N: usize> From<I<N>> for BigInt {
+    fn from(val: I<N>) -> Self {
+        BigInt::from_le_bytes(&val.unsigned_abs().to_le_bytes(), val.is_negative())
+    }
+}
+
+impl<const N: usize> U<N> {
+    /// Convert this value into a [`BigInt`]. This is a shortcut for the `From` implementation,
+    /// usable without naming the type.
+    #[must_use]
+    pub fn to_bigint(&self) -> BigInt {
+        BigInt::from(*self)
+    }
+}
+
+impl<const N: usize> I<N> {
+    /// Convert this value into a [`BigInt`]. This is a shortcut for the `From` implementation,
+    /// usable without naming the type.
+    #[must_use]
+    pub fn to_bigint(&self) -> BigInt {
+        BigInt::from(*self)
+    }
+}
+
+impl<const N: usize> TryFrom<&BigInt> for U<N> {
+    type Error = OutOfRangeError;
+
+    fn try_from(bi: &BigInt) -> Result<Self, Self::Error> {
+        if bi.is_negative() {
+            return Err(OutOfRangeError::below());
+        }
+        U::try_from_le_slice(&bi.magnitude_bytes()).map_err(|_| OutOfRangeError::above())
+    }
+}
+
+impl<const N: usize> TryFrom<BigInt> for U<N> {
+    type Error = OutOfRangeError;
+
+    fn try_from(bi: BigInt) -> Result<Self, Self::Error> {
+        U::try_from(&bi)
+    }
+}
+
+impl<const N: usize> TryFrom<&BigInt> for I<N> {
+    type Error = OutOfRangeError;
+
+    fn try_from(bi: &BigInt) -> Result<Self, Self::Error> {
+        let out_of_range = || {
+            if bi.is_negative() {
+                OutOfRangeError::below()
+            } else {
+                OutOfRangeError::above()
+            }
+        };
+
+        let magnitude: U<N> =
+            U::try_from_le_slice(&bi.magnitude_bytes()).map_err(|_| out_of_range())?;
+        let out = I::from_le_bytes(magnitude.to_le_bytes());
+        let out = if bi.is_negative() { -out } else { out };
+
+        // If the magnitude needed the sign bit, the result will have the wrong sign. The only
+        // exception is the minimum value, which negates to itself.
+        if !bi.is_zero() && out.is_negative() != bi.is_negative() {
+            Err(out_of_range())
+        } else {
+            Ok(out)
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<BigInt> for I<N> {
+    type Error = OutOfRangeError;
+
+    fn try_from(bi: BigInt) -> Result<Self, Self::Error> {
+        I::try_from(&bi)
+    }
+}
+
 const fn arr_size<T>() -> usize {
     (mem::size_of::<T>() / mem::size_of::<usize>()) + 1
 }
@@ -430,21 +1152,93 @@ impl_for_int!(i64, u64);
 impl_for_int!(i128, u128);
 impl_for_int!(isize, usize);
 
-impl_op!(add(self, rhs) => {
-    let (out, neg) = BigInt::with_slices(self, rhs, |this, other| {
-        match (self.is_positive(), rhs.is_positive()) {
-            (true, true) | (false, false) => {
-                (ElementAdd::add(this, other), self.is_negative())
-            }
-            (true, _) => {
-                let (out, neg) = ElementSub::sub(this, other);
-                (out, neg)
+/// Add two sign-magnitude values, returning the magnitude and sign of the result. This is the
+/// core of addition and subtraction for both [`BigInt`] and [`BigIntIn`].
+fn add_limbs(
+    this: &[usize],
+    this_neg: bool,
+    other: &[usize],
+    other_neg: bool,
+) -> (Vec<usize>, bool) {
+    if this_neg == other_neg {
+        (ElementAdd::add(this, other), this_neg)
+    } else {
+        // The magnitude of the result is the difference, which takes the sign of the larger value
+        let (out, neg) = ElementSub::sub(this, other);
+        (out, neg != this_neg)
+    }
+}
+
+/// Calculate `limbs * mul + add` in place, in a single pass. Used to build up a value digit by
+/// digit, without interning every intermediate value.
+fn mul_add_limbs(limbs: &mut Vec<usize>, mul: usize, add: usize) {
+    let mut carry = add;
+    for limb in limbs.iter_mut() {
+        let (low, high) = WideningMul::widening_mul(*limb, mul, carry);
+        *limb = low;
+        carry = high;
+    }
+    if carry != 0 {
+        limbs.push(carry);
+    }
+}
+
+/// Add a single-limb value to a sign-magnitude value, returning the magnitude and sign of the
+/// result. The carry or borrow stops propagating as soon as it reaches zero.
+fn add_usize_limbs(
+    this: &[usize],
+    this_neg: bool,
+    other: usize,
+    other_neg: bool,
+) -> (Vec<usize>, bool) {
+    if this_neg == other_neg {
+        let mut out = this.to_vec();
+        let mut carry = other;
+        for limb in &mut out {
+            let (sum, overflow) = limb.overflowing_add(carry);
+            *limb = sum;
+            carry = usize::from(overflow);
+            if carry == 0 {
+                break;
             }
-            (_, true) => {
-                let (out, neg) = ElementSub::sub(this, other);
-                (out, !neg)
+        }
+        if carry != 0 {
+            out.push(carry);
+        }
+        (out, this_neg)
+    } else if this.len() == 1 && this[0] < other {
+        // The magnitude is smaller than `other`, so the result takes its sign
+        (vec![other - this[0]], other_neg)
+    } else {
+        let mut out = this.to_vec();
+        let mut borrow = other;
+        for limb in &mut out {
+            let (diff, underflow) = limb.overflowing_sub(borrow);
+            *limb = diff;
+            borrow = usize::from(underflow);
+            if borrow == 0 {
+                break;
             }
         }
+        (out, this_neg)
+    }
+}
+
+/// Divide two sign-magnitude values, returning the quotient and remainder along with their signs.
+/// Division truncates towards zero, so the remainder takes the sign of the dividend.
+fn div_limbs(
+    this: &[usize],
+    this_neg: bool,
+    other: &[usize],
+    other_neg: bool,
+) -> ((Vec<usize>, bool), (Vec<usize>, bool)) {
+    let (quot, rem) = ElementDiv::div_long(this, other);
+    ((quot, this_neg != other_neg), (rem, this_neg))
+}
+
+impl_op!(add(self, rhs) => {
+    let (out, neg) = BigInt::with_slices(self, rhs, |this, other| {
+        add_limbs(this, self.is_negative(), other, rhs.is_negative())
     });
 
     BigInt::new_slice(out, neg)
@@ -460,53 +1254,96 @@ impl_op!(mul(self, rhs) => {
 
 impl_op!(sub(self, rhs) => {
     let (out, neg) = BigInt::with_slices(self, rhs, |this, other| {
-        match (self.is_positive(), rhs.is_positive()) {
-            (true, false) | (false, true) => {
-                let out = ElementAdd::add(this, other);
-                (out, self.is_negative())
-            }
-            (true, true) => {
-                let (out, neg) = ElementSub::sub(this, other);
-                (out, neg)
-            }
-            (false, false) => {
-                let (out, neg) = ElementSub::sub(this, other);
-                (out, !neg)
-            }
-        }
+        add_limbs(this, self.is_negative(), other, !rhs.is_negative())
     });
 
     BigInt::new_slice(out, neg)
 });
 
 impl_op!(div(self, rhs) => {
-    let out = BigInt::with_slices(self, rhs, |this, other| {
-        BitwiseDiv::div_long(this, other).0
+    let ((out, neg), _) = BigInt::with_slices(self, rhs, |this, other| {
+        div_limbs(this, self.is_negative(), other, rhs.is_negative())
     });
-    BigInt::new_slice(out, self.is_negative() != rhs.is_negative())
+    BigInt::new_slice(out, neg)
 });
 
 impl_op!(rem(self, rhs) => {
-    let out = BigInt::with_slices(self, rhs, |this, other| {
-        BitwiseDiv::div_long(this, other).1
+    let (_, (out, neg)) = BigInt::with_slices(self, rhs, |this, other| {
+        div_limbs(this, self.is_negative(), other, rhs.is_negative())
     });
-    BigInt::new_slice(out, self.is_negative() != rhs.is_negative())
+    BigInt::new_slice(out, neg)
 });
 
 impl_op!(shl(self, rhs) => {
-    let out = BigInt::with_slices(self, rhs, |this, _| {
-        ElementShl::shl(this, usize::try_from(rhs).expect("Shifts larger than a usize are not yet supported"))
-    });
-    BigInt::new_slice(out, self.is_negative())
+    self.checked_shl(rhs)
+        .expect("Left shift amount must be non-negative and fit in a usize")
 });
 
+// Shifting right by more than the bit length of the value will always produce zero, so an amount
+// too large for a usize doesn't need to be supported by the underlying slice operation.
 impl_op!(shr(self, rhs) => {
-    let out = BigInt::with_slices(self, rhs, |this, _| {
-        ElementShr::shr(this, usize::try_from(rhs).expect("Shifts larger than a usize are not yet supported"))
-    });
-    BigInt::new_slice(out, self.is_negative())
+    assert!(!rhs.is_negative(), "Right shift amount must be non-negative");
+    match usize::try_from(rhs) {
+        Ok(shift) => {
+            let out = self.with_slice(|this| ElementShr::shr(this, shift));
+            BigInt::new_slice(out, self.is_negative())
+        }
+        Err(_) => BigInt::new(),
+    }
 });
 
+impl CheckedShl<&BigInt> for &BigInt {
+    type Output = BigInt;
+
+    /// Shift this value left, returning `None` if the shift amount is negative or too large to fit
+    /// in a `usize`. The result of such a shift couldn't fit in memory anyways.
+    fn checked_shl(self, rhs: &BigInt) -> Option<BigInt> {
+        let shift = usize::try_from(rhs).ok()?;
+        let out = self.with_slice(|this| ElementShl::shl(this, shift));
+        Some(BigInt::new_slice(out, self.is_negative()))
+    }
+}
+
+impl CheckedShl for BigInt {
+    type Output = BigInt;
+
+    fn checked_shl(self, rhs: BigInt) -> Option<BigInt> {
+        (&self).checked_shl(&rhs)
+    }
+}
+
+macro_rules! impl_checked {
+    ($trait:ident, $meth:ident, $op:tt, |$rhs:ident| $check:expr) => {
+        impl $trait<&BigInt> for &BigInt {
+            type Output = BigInt;
+
+            fn $meth(self, $rhs: &BigInt) -> Option<BigInt> {
+                if $check {
+                    Some(self $op $rhs)
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl $trait for BigInt {
+            type Output = BigInt;
+
+            fn $meth(self, rhs: BigInt) -> Option<BigInt> {
+                (&self).$meth(&rhs)
+            }
+        }
+    };
+}
+
+// Big integers can't overflow, so these only fail on division by zero. They exist so `BigInt` can
+// be used with code generic over the checked operations.
+impl_checked!(CheckedAdd, checked_add, +, |_rhs| true);
+impl_checked!(CheckedSub, checked_sub, -, |_rhs| true);
+impl_checked!(CheckedMul, checked_mul, *, |_rhs| true);
+impl_checked!(CheckedDiv, checked_div, /, |rhs| !rhs.is_zero());
+impl_checked!(CheckedRem, checked_rem, %, |rhs| !rhs.is_zero());
+
 impl_op!(bitand(self, rhs) => {
     let out = BigInt::with_slices(self, rhs, |this, other| {
         ElementBitand::bitand(this, other)
@@ -545,7 +1382,10 @@ impl ops::Neg for BigInt {
     type Output = BigInt;
 
     fn neg(mut self) -> Self::Output {
-        self.0 = self.0.invert_neg();
+        // Zero is never negative
+        if !self.is_zero() {
+            self.0 = self.0.invert_neg();
+        }
         self
     }
 }
@@ -554,9 +1394,7 @@ impl ops::Neg for &BigInt {
     type Output = BigInt;
 
     fn neg(self) -> Self::Output {
-        let mut out = self.clone();
-        out.0 = out.0.invert_neg();
-        out
+        -self.clone()
     }
 }
 
@@ -615,20 +1453,26 @@ impl One for BigInt {
     }
 }
 
-/// The error for when you try to create a `BigInt` from a string and either the radix is invalid,
-/// or the string contains invalid characters.
-#[derive(Debug)]
+/// The error for when you try to create a `BigInt` from a string or digits and either the radix is
+/// invalid, or the input contains invalid characters or digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum FromStrError {
     /// Radix was outside the valid range for conversion
     InvalidRadix(u32),
-    /// Character wasn't a valid digit for the provided radix
-    InvalidChar(char),
+    /// Character wasn't a valid digit for the provided radix, along with its byte index in the
+    /// input string
+    InvalidChar(char, usize),
+    /// Digit value wasn't less than the provided radix, along with its index in the input digits
+    InvalidDigit(u8, usize),
+    /// Input contained no digits, such as an empty string, a lone sign, or a bare radix prefix
+    Empty,
 }
 
 struct RadixChars;
 
 impl RadixChars {
-    fn val_from_char(c: char, radix: u32) -> Result<u32, FromStrError> {
+    fn val_from_char(c: char, idx: usize, radix: u32) -> Result<u32, FromStrError> {
         static INSENS_CHARS: &[char] = &[
             '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'g',
             'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x',
@@ -641,14 +1485,14 @@ impl RadixChars {
                 chars
                     .iter()
                     .enumerate()
-                    .find_map(|(idx, &c2)| {
+                    .find_map(|(val, &c2)| {
                         if c2 == c.to_ascii_lowercase() {
-                            Some(u32::try_from(idx).unwrap())
+                            Some(u32::try_from(val).unwrap())
                         } else {
                             None
                         }
                     })
-                    .ok_or(FromStrError::InvalidChar(c))
+                    .ok_or(FromStrError::InvalidChar(c, idx))
             }
             _ => Err(FromStrError::InvalidRadix(radix)),
         }
@@ -659,19 +1503,43 @@ impl FromStrRadix for BigInt {
     type Error = FromStrError;
 
     fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::Error> {
-        let mut out = BigInt::zero();
-        for digit in str.chars() {
-            let new_val = RadixChars::val_from_char(digit, radix)?;
-            out = (out * radix) + new_val;
-        }
-        Ok(out)
+        let (neg, digits) = match str.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, str),
+        };
+        let out = parse_digits(digits, str.len() - digits.len(), radix)?;
+        Ok(if neg { -out } else { out })
+    }
+}
+
+/// Parse the unsigned digits of a value in the given radix. `start` is the byte index of the
+/// digits in the full input, used to report the position of invalid characters.
+fn parse_digits(digits: &str, start: usize, radix: u32) -> Result<BigInt, FromStrError> {
+    if digits.is_empty() {
+        return Err(FromStrError::Empty);
+    }
+    let mut limbs = vec![0];
+    for (idx, digit) in digits.char_indices() {
+        let new_val = RadixChars::val_from_char(digit, start + idx, radix)?;
+        mul_add_limbs(&mut limbs, radix as usize, new_val as usize);
     }
+    Ok(BigInt::new_slice(limbs, false))
 }
 
 impl Numeric for BigInt {}
 
 impl Integral for BigInt {}
 
+impl MaybeBounded for BigInt {
+    fn try_min_value() -> Option<Self> {
+        None
+    }
+
+    fn try_max_value() -> Option<Self> {
+        None
+    }
+}
+
 impl Signed for BigInt {
     fn abs(self) -> Self {
         if self.is_negative() {
@@ -704,6 +1572,31 @@ impl Signed for BigInt {
     }
 }
 
+impl Gcd for BigInt {
+    type Output = BigInt;
+
+    fn gcd(self, rhs: BigInt) -> BigInt {
+        let (mut a, mut b) = (self.abs(), rhs.abs());
+        while !b.is_zero() {
+            let rem = &a % &b;
+            a = mem::replace(&mut b, rem);
+        }
+        a
+    }
+}
+
+impl Lcm for BigInt {
+    type Output = BigInt;
+
+    fn lcm(self, rhs: BigInt) -> BigInt {
+        if self.is_zero() || rhs.is_zero() {
+            return BigInt::zero();
+        }
+        let gcd = self.clone().gcd(rhs.clone());
+        (self / gcd * rhs).abs()
+    }
+}
+
 impl Pow<BigInt> for BigInt {
     type Output = BigInt;
 
@@ -725,7 +1618,9 @@ impl Pow<BigInt> for BigInt {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
     use alloc::string::ToString;
+    use numeric_traits::class::Bounded;
 
     #[test]
     fn test_new() {
@@ -744,6 +1639,606 @@ mod tests {
         assert_eq!(BigInt::new_slice(&[0usize] as &[_], true), BigInt::from(0));
     }
 
+    #[test]
+    fn test_neg_zero() {
+        let zero = -BigInt::zero();
+        assert_eq!(zero, BigInt::zero());
+        assert!(!zero.is_negative());
+        assert!(zero.is_zero());
+
+        let zero = -&BigInt::zero();
+        assert!(!zero.is_negative());
+
+        let big = BigInt::from_limbs(&[0, 1], true);
+        let zero = -(&big - &big);
+        assert!(!zero.is_negative());
+        assert!(zero.is_zero());
+    }
+
+    #[test]
+    fn test_sign() {
+        assert_eq!(BigInt::from(-5).sign(), Sign::Negative);
+        assert_eq!(BigInt::zero().sign(), Sign::Zero);
+        assert_eq!(BigInt::from(5).sign(), Sign::Positive);
+        assert_eq!(BigInt::from_limbs(&[0, 1], true).sign(), Sign::Negative);
+        assert_eq!(BigInt::from_limbs(&[0, 1], false).sign(), Sign::Positive);
+
+        let big = BigInt::from_limbs(&[0, 1], true);
+        assert_eq!((&big - &big).sign(), Sign::Zero);
+        assert_eq!((BigInt::from(3) * -BigInt::zero()).sign(), Sign::Zero);
+        assert_eq!((BigInt::from(-7) % BigInt::from(7)).sign(), Sign::Zero);
+    }
+
+    #[test]
+    fn test_limbs() {
+        let vals: &[(&[usize], bool)] = &[
+            (&[0], false),
+            (&[1], true),
+            (&[usize::MAX >> 2], false),
+            (&[usize::MAX], true),
+            (&[0, 1], false),
+            (&[1, 2, 3], true),
+        ];
+        for &(limbs, neg) in vals {
+            let val = BigInt::from_limbs(limbs, neg);
+            assert_eq!(val.is_negative(), neg);
+            val.with_limbs(|out| assert_eq!(out, limbs));
+        }
+
+        let val = BigInt::from_limbs(&[5, 0, 0], true);
+        assert!(val.is_inline());
+        val.with_limbs(|out| assert_eq!(out, &[5]));
+
+        assert_eq!(BigInt::from_limbs(&[], true), BigInt::zero());
+        assert_eq!(BigInt::from_limbs(&[0, 0], true), BigInt::zero());
+        assert!(BigInt::from_limbs(&[0, 0], true).is_positive());
+    }
+
+    #[test]
+    fn test_factorial() {
+        assert_eq!(BigInt::factorial(0), 1);
+        assert_eq!(BigInt::factorial(1), 1);
+        assert_eq!(BigInt::factorial(5), 120);
+        assert_eq!(BigInt::factorial(20), 2_432_902_008_176_640_000u64);
+        assert_eq!(
+            BigInt::factorial(30).to_string(),
+            "265252859812191058636308480000000"
+        );
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(BigInt::binomial(10, 3), 120);
+        assert_eq!(BigInt::binomial(10, 7), 120);
+        assert_eq!(BigInt::binomial(10, 0), 1);
+        assert_eq!(BigInt::binomial(10, 10), 1);
+        assert_eq!(BigInt::binomial(3, 4), 0);
+        assert_eq!(
+            BigInt::binomial(100, 50).to_string(),
+            "100891344545564193334812497256"
+        );
+    }
+
+    #[test]
+    fn test_try_into_u() {
+        assert_eq!(
+            U::<4>::try_from(BigInt::from(0x1234_5678)),
+            Ok(U::from_u32(0x1234_5678))
+        );
+        assert_eq!(U::<4>::try_from(&BigInt::zero()), Ok(U::new()));
+        assert_eq!(
+            U::<4>::try_from(BigInt::from(u32::MAX)),
+            Ok(U::from_u32(u32::MAX))
+        );
+        assert!(U::<4>::try_from(BigInt::from(u64::from(u32::MAX) + 1)).is_err());
+        assert_eq!(
+            U::<4>::try_from(BigInt::from(-1)),
+            Err(OutOfRangeError::below())
+        );
+
+        let big = BigInt::from(u128::MAX);
+        assert_eq!(U::<16>::try_from(&big), Ok(U::from_u128(u128::MAX)));
+        assert!(U::<8>::try_from(&big).is_err());
+    }
+
+    #[test]
+    fn test_try_into_i() {
+        let i16 = |val: i16| I::from_le_bytes(val.to_le_bytes());
+        assert_eq!(I::<2>::try_from(BigInt::from(1234)), Ok(i16(1234)));
+        assert_eq!(I::<2>::try_from(BigInt::from(-1234)), Ok(i16(-1234)));
+        assert_eq!(I::<2>::try_from(BigInt::from(i16::MAX)), Ok(i16(i16::MAX)));
+        assert_eq!(I::<2>::try_from(BigInt::from(i16::MIN)), Ok(i16(i16::MIN)));
+        assert_eq!(I::<2>::try_from(BigInt::zero()), Ok(i16(0)));
+        assert_eq!(
+            I::<2>::try_from(BigInt::from(i32::from(i16::MAX) + 1)),
+            Err(OutOfRangeError::above())
+        );
+        assert_eq!(
+            I::<2>::try_from(BigInt::from(i32::from(i16::MIN) - 1)),
+            Err(OutOfRangeError::below())
+        );
+        assert!(I::<2>::try_from(BigInt::from(u16::MAX)).is_err());
+        assert!(I::<2>::try_from(BigInt::from(-i32::from(u16::MAX))).is_err());
+    }
+
+    #[test]
+    fn test_from_u_i() {
+        assert_eq!(BigInt::from(U::<1>::from_u8(200)), 200);
+        assert_eq!(
+            BigInt::from(U::<16>::from_u128(u128::MAX)),
+            BigInt::from(u128::MAX)
+        );
+        assert_eq!(BigInt::from(U::<3>::new()), 0);
+
+        let i16 = |val: i16| I::<2>::from_le_bytes(val.to_le_bytes());
+        assert_eq!(BigInt::from(i16(-1234)), -1234);
+        assert_eq!(BigInt::from(i16(i16::MIN)), i16::MIN);
+        assert_eq!(BigInt::from(i16(i16::MAX)), i16::MAX);
+
+        let val = U::<32>::max_value() - U::from_le_bytes([7; 32]);
+        assert_eq!(U::try_from(BigInt::from(val)), Ok(val));
+        let val = I::<32>::min_value() + I::one();
+        assert_eq!(I::try_from(BigInt::from(val)), Ok(val));
+        for val in [i16::MIN, -300, -1, 0, 1, 300, i16::MAX] {
+            assert_eq!(I::try_from(BigInt::from(i16(val))), Ok(i16(val)));
+        }
+    }
+
+    #[test]
+    fn test_div_rem() {
+        for (l, r) in [(7, 2), (-7, 2), (7, -2), (-7, -2), (6, 3), (0, 5), (1, 9)] {
+            let (quot, rem) = BigInt::from(l).div_rem(&BigInt::from(r));
+            assert_eq!(
+                (quot, rem),
+                (BigInt::from(l / r), BigInt::from(l % r)),
+                "{l} / {r}"
+            );
+            assert_eq!(BigInt::from(l) % BigInt::from(r), l % r, "{l} % {r}");
+        }
+    }
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(BigInt::from(48).gcd(BigInt::from(18)), 6);
+        assert_eq!(BigInt::from(-48).gcd(BigInt::from(18)), 6);
+        assert_eq!(BigInt::from(0).gcd(BigInt::from(-5)), 5);
+        assert_eq!(BigInt::from(17).gcd(BigInt::from(5)), 1);
+        assert_eq!(BigInt::from(4).lcm(BigInt::from(-6)), 12);
+        assert_eq!(BigInt::from(0).lcm(BigInt::from(6)), 0);
+    }
+
+    #[test]
+    fn test_gcd_extended() {
+        let big = BigInt::from(u128::MAX) * BigInt::from(0x1234_5678_9ABC_DEF0u64);
+        let pairs = [
+            (BigInt::from(240), BigInt::from(46)),
+            (BigInt::from(-240), BigInt::from(46)),
+            (BigInt::from(240), BigInt::from(-46)),
+            (BigInt::from(17), BigInt::from(5)),
+            (BigInt::from(0), BigInt::from(9)),
+            (BigInt::from(9), BigInt::from(0)),
+            (big.clone(), BigInt::from(u64::MAX)),
+            (big.clone() + 1, big),
+        ];
+        for (a, b) in pairs {
+            let (g, x, y) = a.gcd_extended(&b);
+            assert_eq!(&a * &x + &b * &y, g, "{a}, {b}");
+            assert_eq!(g, a.clone().gcd(b.clone()), "{a}, {b}");
+        }
+        assert_eq!(BigInt::zero().gcd_extended(&BigInt::zero()).0, 0);
+    }
+
+    #[test]
+    fn test_modinv() {
+        assert_eq!(
+            BigInt::from(3).modinv(&BigInt::from(11)),
+            Some(BigInt::from(4))
+        );
+        assert_eq!(
+            BigInt::from(10).modinv(&BigInt::from(17)),
+            Some(BigInt::from(12))
+        );
+        assert_eq!(
+            BigInt::from(-3).modinv(&BigInt::from(11)),
+            Some(BigInt::from(7))
+        );
+        assert_eq!(
+            BigInt::from(14).modinv(&BigInt::from(11)),
+            Some(BigInt::from(4))
+        );
+        assert_eq!(BigInt::from(6).modinv(&BigInt::from(9)), None);
+        assert_eq!(BigInt::from(0).modinv(&BigInt::from(9)), None);
+        assert_eq!(BigInt::from(5).modinv(&BigInt::one()), Some(BigInt::zero()));
+
+        let modulus = (BigInt::one() << BigInt::from(127)) - 1;
+        let val = BigInt::from(u64::MAX);
+        let inv = val.modinv(&modulus).unwrap();
+        assert!(inv < modulus && !inv.is_negative());
+        assert_eq!(val * inv % &modulus, 1);
+    }
+
+    #[test]
+    #[should_panic = "Modulus must be positive"]
+    fn test_modinv_zero() {
+        let _ = BigInt::from(3).modinv(&BigInt::zero());
+    }
+
+    #[test]
+    fn test_one_shl() {
+        assert_eq!(BigInt::one_shl(0), 1);
+        assert_eq!(BigInt::one_shl(10), 1024);
+        assert_eq!(BigInt::one_shl(64), BigInt::from(u64::MAX) + 1);
+        for k in [1, 31, 62, 63, 64, 65, 127, 128, 200] {
+            assert_eq!(
+                BigInt::one_shl(k),
+                BigInt::one() << BigInt::from(k),
+                "1 << {k}"
+            );
+        }
+        // Mersenne numbers
+        assert_eq!(BigInt::one_shl(7) - 1, 127);
+    }
+
+    #[test]
+    fn test_mul_add() {
+        let big = BigInt::from;
+        assert_eq!(big(3).mul_add(&big(4), &big(5)), 17);
+        assert_eq!(big(-3).mul_add(&big(4), &big(5)), -7);
+        assert_eq!(big(-3).mul_add(&big(-4), &big(-12)), 0);
+        assert_eq!(big(0).mul_add(&big(4), &big(-5)), -5);
+
+        let max = BigInt::from(u128::MAX);
+        assert_eq!(max.mul_add(&max, &max), &(&max * &max) + &max);
+        assert_eq!(
+            max.mul_add(&-max.clone(), &big(1)),
+            &(&max * &-max.clone()) + &big(1)
+        );
+    }
+
+    #[test]
+    fn test_to_bigint() {
+        let val = I::<4>::from_le_bytes((-1000i32).to_le_bytes());
+        assert_eq!(val.to_bigint(), BigInt::from(-1000));
+        assert_eq!(I::<16>::MIN.to_bigint(), BigInt::from(i128::MIN));
+        assert_eq!(U::<4>::from_u32(1000).to_bigint(), BigInt::from(1000));
+        assert_eq!(U::<16>::MAX.to_bigint(), BigInt::from(u128::MAX));
+    }
+
+    #[test]
+    fn test_from_digits() {
+        assert_eq!(BigInt::from_digits(&[15, 15], 16), Ok(BigInt::from(255)));
+        assert_eq!(BigInt::from_digits(&[1, 0, 1, 0], 2), Ok(BigInt::from(10)));
+        assert_eq!(BigInt::from_digits(&[1, 2, 3], 10), Ok(BigInt::from(123)));
+        assert_eq!(
+            BigInt::from_digits(&[255, 255], 256),
+            Ok(BigInt::from(0xFFFF))
+        );
+        assert_eq!(BigInt::from_digits(&[], 10), Ok(BigInt::zero()));
+        assert_eq!(
+            BigInt::from_digits(&[1; 40], 10),
+            BigInt::from_str_radix(&"1".repeat(40), 10)
+        );
+
+        assert_eq!(
+            BigInt::from_digits(&[1, 16, 2], 16),
+            Err(FromStrError::InvalidDigit(16, 1))
+        );
+        assert_eq!(
+            BigInt::from_digits(&[0], 1),
+            Err(FromStrError::InvalidRadix(1))
+        );
+    }
+
+    #[test]
+    fn test_from_str_prefixed() {
+        assert_eq!(BigInt::from_str_prefixed("0xFF"), Ok(BigInt::from(255)));
+        assert_eq!(BigInt::from_str_prefixed("0o17"), Ok(BigInt::from(15)));
+        assert_eq!(BigInt::from_str_prefixed("0b1010"), Ok(BigInt::from(10)));
+        assert_eq!(BigInt::from_str_prefixed("1234"), Ok(BigInt::from(1234)));
+        assert_eq!(BigInt::from_str_prefixed("-0x10"), Ok(BigInt::from(-16)));
+        assert_eq!(BigInt::from_str_prefixed("+0b11"), Ok(BigInt::from(3)));
+        assert_eq!(BigInt::from_str_prefixed("-42"), Ok(BigInt::from(-42)));
+        assert_eq!(
+            BigInt::from_str_prefixed("0x10000000000000000"),
+            Ok(BigInt::from(1u128 << 64))
+        );
+
+        assert_eq!(
+            BigInt::from_str_prefixed("0b102"),
+            Err(FromStrError::InvalidChar('2', 4))
+        );
+        assert_eq!(
+            BigInt::from_str_prefixed("-0x-1"),
+            Err(FromStrError::InvalidChar('-', 3))
+        );
+        assert_eq!(
+            BigInt::from_str_prefixed("0xg"),
+            Err(FromStrError::InvalidChar('g', 2))
+        );
+        assert_eq!(BigInt::from_str_prefixed(""), Err(FromStrError::Empty));
+        assert_eq!(BigInt::from_str_prefixed("-"), Err(FromStrError::Empty));
+        assert_eq!(BigInt::from_str_prefixed("0x"), Err(FromStrError::Empty));
+        assert_eq!(BigInt::from_str_prefixed("+0b"), Err(FromStrError::Empty));
+    }
+
+    #[test]
+    fn test_maybe_bounded() {
+        // Double a value, clamping to the maximum if the type has one
+        fn double<T: Integral + MaybeBounded>(val: T) -> T {
+            match T::try_max_value() {
+                Some(max) if val > max.clone() - val.clone() => max,
+                _ => val.clone() + val,
+            }
+        }
+
+        assert_eq!(double(5u32), 10);
+        assert_eq!(double(u32::MAX - 1), u32::MAX);
+        assert_eq!(double(U::<4>::from_u32(5)), U::<4>::from_u32(10));
+        assert_eq!(double(U::<4>::MAX - U::<4>::from_u32(1)), U::<4>::MAX);
+        assert_eq!(double(BigInt::from(5)), 10);
+        assert_eq!(
+            double(BigInt::from(u32::MAX)),
+            BigInt::from(u64::from(u32::MAX) * 2)
+        );
+
+        assert_eq!(<U<4>>::try_min_value(), Some(U::MIN));
+        assert_eq!(BigInt::try_min_value(), None);
+        assert_eq!(BigInt::try_max_value(), None);
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        let vals = [
+            BigInt::from(0),
+            BigInt::from(7),
+            BigInt::from(-3),
+            BigInt::from(u128::MAX),
+            -BigInt::from(u128::MAX) * 5,
+        ];
+        for a in &vals {
+            for b in &vals {
+                assert_eq!(a.checked_add(b), Some(a + b));
+                assert_eq!(a.checked_sub(b), Some(a - b));
+                assert_eq!(a.checked_mul(b), Some(a * b));
+                if b.is_zero() {
+                    assert_eq!(a.checked_div(b), None);
+                    assert_eq!(a.checked_rem(b), None);
+                } else {
+                    assert_eq!(a.checked_div(b), Some(a / b));
+                    assert_eq!(a.checked_rem(b), Some(a % b));
+                }
+            }
+        }
+        assert_eq!(
+            CheckedAdd::checked_add(BigInt::from(2), BigInt::from(3)),
+            Some(BigInt::from(5))
+        );
+        assert_eq!(
+            CheckedDiv::checked_div(BigInt::from(2), BigInt::zero()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_abs_diff() {
+        assert_eq!(BigInt::from(3).abs_diff(&BigInt::from(10)), 7);
+        assert_eq!(BigInt::from(10).abs_diff(&BigInt::from(3)), 7);
+        assert_eq!(BigInt::from(-5).abs_diff(&BigInt::from(5)), 10);
+        assert_eq!(BigInt::from(5).abs_diff(&BigInt::from(-5)), 10);
+        assert_eq!(BigInt::from(-3).abs_diff(&BigInt::from(-10)), 7);
+        assert_eq!(BigInt::from(4).abs_diff(&BigInt::from(4)), 0);
+
+        let big = BigInt::from(u128::MAX);
+        assert_eq!(big.abs_diff(&-big.clone()), &big * &BigInt::from(2));
+        assert_eq!(
+            (-big.clone()).abs_diff(&BigInt::one()),
+            &big + &BigInt::one()
+        );
+    }
+
+    #[test]
+    fn test_try_pow() {
+        assert_eq!(BigInt::from(3).try_pow(4, 64), Some(BigInt::from(81)));
+        assert_eq!(BigInt::from(-2).try_pow(3, 64), Some(BigInt::from(-8)));
+        assert_eq!(BigInt::from(7).try_pow(0, 0), Some(BigInt::one()));
+        assert_eq!(
+            BigInt::from(10).try_pow(40, 256),
+            Some(BigInt::from_str_radix("10000000000000000000000000000000000000000", 10).unwrap())
+        );
+        assert_eq!(BigInt::from(10).try_pow(40, 100), None);
+
+        // Values which don't grow are always allowed
+        assert_eq!(BigInt::one().try_pow(u32::MAX, 1), Some(BigInt::one()));
+        assert_eq!(
+            BigInt::from(-1).try_pow(u32::MAX, 1),
+            Some(BigInt::from(-1))
+        );
+        assert_eq!(BigInt::zero().try_pow(u32::MAX, 1), Some(BigInt::zero()));
+
+        // This would need around 2^38 bits, and must be rejected without trying
+        let huge = BigInt::from(u64::MAX);
+        assert_eq!(huge.try_pow(u32::MAX, 1 << 20), None);
+        assert_eq!(huge.try_pow(u32::MAX, 1 << 37), None);
+    }
+
+    #[test]
+    fn test_sqrt_rem() {
+        assert_eq!(
+            BigInt::from(17).sqrt_rem(),
+            (BigInt::from(4), BigInt::from(1))
+        );
+        assert_eq!(
+            BigInt::from(16).sqrt_rem(),
+            (BigInt::from(4), BigInt::zero())
+        );
+        assert_eq!(BigInt::zero().sqrt_rem(), (BigInt::zero(), BigInt::zero()));
+        assert_eq!(BigInt::one().sqrt_rem(), (BigInt::one(), BigInt::zero()));
+
+        for n in 0u64..1000 {
+            let root = (0..).take_while(|r| r * r <= n).last().unwrap();
+            assert_eq!(
+                BigInt::from(n).sqrt_rem(),
+                (BigInt::from(root), BigInt::from(n - root * root))
+            );
+        }
+
+        let big = BigInt::from(u128::MAX);
+        let (root, rem) = (&big * &big).sqrt_rem();
+        assert_eq!(root, big);
+        assert!(rem.is_zero());
+        let (root, rem) = (&(&big * &big) - &BigInt::one()).sqrt_rem();
+        assert_eq!(root, &big - &BigInt::one());
+        assert_eq!(rem, &(&big * &BigInt::from(2)) - &BigInt::from(2));
+    }
+
+    #[test]
+    #[should_panic = "Can't take the square root of a negative number"]
+    fn test_sqrt_rem_negative() {
+        let _ = BigInt::from(-4).sqrt_rem();
+    }
+
+    #[test]
+    fn test_sqrt_mod() {
+        for p in [3u32, 5, 7, 11, 13, 17, 41, 97, 113] {
+            let p = BigInt::from(p);
+            let mut residues = 0;
+            let mut val = BigInt::zero();
+            while val < p {
+                let is_residue = {
+                    let mut x = BigInt::zero();
+                    let mut found = false;
+                    while x < p {
+                        if &x * &x % &p == val {
+                            found = true;
+                        }
+                        x += BigInt::one();
+                    }
+                    found
+                };
+                match val.sqrt_mod(&p) {
+                    Some(r) => {
+                        assert!(is_residue);
+                        assert!(r < p && !r.is_negative());
+                        assert_eq!(&r * &r % &p, val);
+                        residues += 1;
+                    }
+                    None => assert!(!is_residue),
+                }
+                val += BigInt::one();
+            }
+            // Zero, plus half of the non-zero values
+            assert_eq!(
+                BigInt::from(residues),
+                (&p + BigInt::one()) >> BigInt::one()
+            );
+        }
+
+        let p = BigInt::from(13);
+        let r = BigInt::from(-3).sqrt_mod(&p).unwrap();
+        assert_eq!(&r * &r % &p, 10);
+
+        // 2^127 - 1 is prime, and 2^127 - 1 == 7 (mod 8), so 2 is a residue
+        let p = (BigInt::one() << BigInt::from(127)) - 1;
+        let val = BigInt::from(u64::MAX);
+        let sq = &val * &val % &p;
+        let r = sq.sqrt_mod(&p).unwrap();
+        assert!(r == val || r == &p - &val);
+        let r = BigInt::from(2).sqrt_mod(&p).unwrap();
+        assert_eq!(&r * &r % &p, 2);
+    }
+
+    #[test]
+    #[should_panic = "Modulus must be an odd prime"]
+    fn test_sqrt_mod_even() {
+        let _ = BigInt::from(3).sqrt_mod(&BigInt::from(8));
+    }
+
+    #[test]
+    fn test_to_f64_exact() {
+        let two_53 = BigInt::one() << BigInt::from(53);
+        assert_eq!(two_53.to_f64_exact(), Some(9_007_199_254_740_992.0));
+        assert_eq!((&two_53 + BigInt::one()).to_f64_exact(), None);
+        assert_eq!((-&two_53).to_f64_exact(), Some(-9_007_199_254_740_992.0));
+        assert_eq!(
+            (&two_53 + &two_53 + BigInt::from(4)).to_f64_exact(),
+            Some(18_014_398_509_481_988.0)
+        );
+        assert_eq!(BigInt::zero().to_f64_exact(), Some(0.0));
+
+        let big = BigInt::one() << BigInt::from(1023);
+        assert_eq!(big.to_f64_exact(), Some(2f64.powi(1023)));
+        assert_eq!((&big + &big).to_f64_exact(), None);
+    }
+
+    #[test]
+    fn test_to_f64_saturating() {
+        let two_53 = BigInt::one() << BigInt::from(53);
+        assert_eq!(two_53.to_f64_saturating(), 9_007_199_254_740_992.0);
+        // Ties round to even
+        assert_eq!(
+            (&two_53 + BigInt::one()).to_f64_saturating(),
+            9_007_199_254_740_992.0
+        );
+        assert_eq!(
+            (&two_53 + BigInt::from(3)).to_f64_saturating(),
+            9_007_199_254_740_996.0
+        );
+        assert_eq!(BigInt::from(-12345).to_f64_saturating(), -12345.0);
+
+        // Anything below the top 64 bits still breaks a tie
+        let tie = (BigInt::one() << BigInt::from(100)) + (BigInt::one() << BigInt::from(47));
+        assert_eq!(tie.to_f64_saturating(), 2f64.powi(100));
+        assert_eq!(
+            (&tie + BigInt::one()).to_f64_saturating(),
+            2f64.powi(100) + 2f64.powi(48)
+        );
+
+        let huge = BigInt::one() << BigInt::from(1024);
+        assert_eq!(huge.to_f64_saturating(), f64::INFINITY);
+        assert_eq!((-huge).to_f64_saturating(), f64::NEG_INFINITY);
+        assert_eq!(
+            BigInt::try_from(f64::MAX).unwrap().to_f64_saturating(),
+            f64::MAX
+        );
+    }
+
+    #[test]
+    fn test_from_float() {
+        assert_eq!(BigInt::try_from(0.0), Ok(BigInt::zero()));
+        assert_eq!(BigInt::try_from(-0.0), Ok(BigInt::zero()));
+        assert_eq!(BigInt::try_from(1.0), Ok(BigInt::from(1)));
+        assert_eq!(BigInt::try_from(2.9), Ok(BigInt::from(2)));
+        assert_eq!(BigInt::try_from(-2.9), Ok(BigInt::from(-2)));
+        assert_eq!(BigInt::try_from(0.5), Ok(BigInt::zero()));
+        assert_eq!(BigInt::try_from(f64::MIN_POSITIVE), Ok(BigInt::zero()));
+        assert_eq!(
+            BigInt::try_from(1e20),
+            Ok(BigInt::from(100_000_000_000_000_000_000u128))
+        );
+        assert_eq!(
+            BigInt::try_from(-(2f64.powi(100))),
+            Ok(-(BigInt::from(1) << BigInt::from(100)))
+        );
+        assert_eq!(
+            BigInt::try_from(f64::MAX),
+            Ok(BigInt::from((1u64 << 53) - 1) << BigInt::from(1024 - 53))
+        );
+        assert_eq!(
+            BigInt::try_from(1e10f32),
+            Ok(BigInt::from(10_000_000_000u64))
+        );
+
+        assert_eq!(BigInt::try_from(f64::NAN), Err(FromFloatError::Nan));
+        assert_eq!(
+            BigInt::try_from(f64::INFINITY),
+            Err(FromFloatError::Infinite)
+        );
+        assert_eq!(
+            BigInt::try_from(f32::NEG_INFINITY),
+            Err(FromFloatError::Infinite)
+        );
+    }
+
     #[test]
     fn test_print() {
         assert_eq!(BigInt::from(1).to_string(), "1");
@@ -753,6 +2248,9 @@ mod tests {
             BigInt::from(18446744073709551616u128).to_string(),
             "18446744073709551616"
         );
+        assert_eq!(BigInt::from(-1).to_string(), "-1");
+        assert_eq!(BigInt::from(-120).to_string(), "-120");
+        assert_eq!(format!("{:x}", BigInt::from(-255)), "-0xff");
     }
 
     #[test]
@@ -762,6 +2260,76 @@ mod tests {
             BigInt::from(123)
         );
         assert_eq!(BigInt::from_str_radix("FF", 16).unwrap(), BigInt::from(255));
+        assert_eq!(
+            BigInt::from_str_radix("-123", 10).unwrap(),
+            BigInt::from(-123)
+        );
+        assert_eq!(BigInt::from_str_radix("-0", 10).unwrap(), BigInt::zero());
+        assert!(BigInt::from_str_radix("1-2", 10).is_err());
+    }
+
+    #[test]
+    fn test_from_str_invalid_index() {
+        assert_eq!(
+            BigInt::from_str_radix("12x4", 10),
+            Err(FromStrError::InvalidChar('x', 2))
+        );
+        assert_eq!(
+            BigInt::from_str_radix("-12x4", 10),
+            Err(FromStrError::InvalidChar('x', 3))
+        );
+        assert_eq!(
+            BigInt::from_str_radix("ä1g", 16),
+            Err(FromStrError::InvalidChar('ä', 0))
+        );
+        assert_eq!(
+            BigInt::from_str_radix("1äg", 16),
+            Err(FromStrError::InvalidChar('ä', 1))
+        );
+        assert_eq!(
+            BigInt::from_str_radix("12", 37),
+            Err(FromStrError::InvalidRadix(37))
+        );
+        assert_eq!(BigInt::from_str_radix("", 10), Err(FromStrError::Empty));
+        assert_eq!(BigInt::from_str_radix("-", 10), Err(FromStrError::Empty));
+    }
+
+    #[test]
+    fn test_usize_ops() {
+        let vals = [
+            BigInt::zero(),
+            BigInt::from(1),
+            BigInt::from(-1),
+            BigInt::from(usize::MAX >> 2),
+            BigInt::from(usize::MAX),
+            -BigInt::from(usize::MAX),
+            BigInt::from_limbs(&[0, 1], false),
+            BigInt::from_limbs(&[0, 1], true),
+            BigInt::from_limbs(&[usize::MAX, usize::MAX], false),
+            BigInt::from_limbs(&[usize::MAX, usize::MAX], true),
+            BigInt::from_limbs(&[5, 0, 7], true),
+        ];
+        let smalls = [
+            0,
+            1,
+            2,
+            5,
+            usize::MAX >> 2,
+            (usize::MAX >> 2) + 1,
+            usize::MAX,
+        ];
+
+        for val in &vals {
+            for &small in &smalls {
+                let big_small = BigInt::from(small);
+                assert_eq!(val.add_usize(small), val + &big_small, "{val} + {small}");
+                assert_eq!(val.sub_usize(small), val - &big_small, "{val} - {small}");
+                assert_eq!(val.mul_usize(small), val * &big_small, "{val} * {small}");
+            }
+        }
+
+        assert!(!BigInt::from(-5).add_usize(5).is_negative());
+        assert!(!BigInt::from(-5).mul_usize(0).is_negative());
     }
 
     #[test]
@@ -838,6 +2406,110 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_trailing_zeros() {
+        assert_eq!(BigInt::from(0).trailing_zeros(), 0);
+        assert_eq!(BigInt::from(1).trailing_zeros(), 0);
+        assert_eq!(BigInt::from(8).trailing_zeros(), 3);
+        assert_eq!(BigInt::from(-8).trailing_zeros(), 3);
+        assert_eq!(BigInt::from(12).trailing_zeros(), 2);
+        assert_eq!(BigInt::from(1u128 << 100).trailing_zeros(), 100);
+        assert_eq!(
+            BigInt::from_limbs(&[0, 0, 0b100], false).trailing_zeros(),
+            u64::from(usize::BITS) * 2 + 2
+        );
+    }
+
+    #[test]
+    fn test_modpow() {
+        let m = BigInt::from(497);
+        assert_eq!(
+            BigInt::from(4).modpow(&BigInt::from(13), &m),
+            BigInt::from(445)
+        );
+        assert_eq!(
+            BigInt::from(4).modpow(&BigInt::from(0), &m),
+            BigInt::from(1)
+        );
+        assert_eq!(
+            BigInt::from(-4).modpow(&BigInt::from(1), &m),
+            BigInt::from(493)
+        );
+        assert_eq!(
+            BigInt::from(5).modpow(&BigInt::from(3), &BigInt::from(1)),
+            BigInt::from(0)
+        );
+
+        // Fermat's little theorem, with intermediates larger than a limb
+        let p = BigInt::from((1u64 << 61) - 1);
+        assert_eq!(
+            BigInt::from(3).modpow(&(&p - BigInt::one()), &p),
+            BigInt::from(1)
+        );
+        let big = BigInt::from(u128::MAX);
+        assert_eq!(
+            big.modpow(&BigInt::from(2), &BigInt::from(1u128 << 100)),
+            BigInt::from(1)
+        );
+    }
+
+    #[test]
+    fn test_is_probably_prime() {
+        for n in [2, 3, 5, 7, 11, 13, 37, 41, 97, 7919] {
+            assert!(BigInt::from(n).is_probably_prime(12), "{n} is prime");
+        }
+        for n in [-7, 0, 1, 4, 9, 15, 91, 561, 1105, 7917] {
+            assert!(!BigInt::from(n).is_probably_prime(12), "{n} is composite");
+        }
+
+        // Mersenne primes 2^61 - 1 and 2^89 - 1
+        assert!(BigInt::from((1u64 << 61) - 1).is_probably_prime(12));
+        assert!(BigInt::from((1u128 << 89) - 1).is_probably_prime(12));
+        // 2^67 - 1 = 193707721 * 761838257287
+        assert!(!BigInt::from((1u128 << 67) - 1).is_probably_prime(12));
+        // Strong pseudoprime to bases 2 and 3
+        assert!(!BigInt::from(1_373_653).is_probably_prime(12));
+    }
+
+    #[test]
+    fn test_shr() {
+        assert_eq!(BigInt::from(4) >> BigInt::from(1), BigInt::from(2));
+        assert_eq!(BigInt::from(5) >> BigInt::from(2), BigInt::from(1));
+        assert_eq!(
+            BigInt::from(u128::MAX) >> BigInt::from(64),
+            BigInt::from(u64::MAX)
+        );
+
+        assert_eq!(BigInt::from(5) >> BigInt::from(3), BigInt::from(0));
+        assert_eq!(BigInt::from(5) >> BigInt::from(200), BigInt::from(0));
+        assert_eq!(
+            BigInt::from(u128::MAX) >> BigInt::from(300),
+            BigInt::from(0)
+        );
+        assert_eq!(BigInt::from(-5) >> BigInt::from(200), BigInt::from(0));
+
+        let huge = BigInt::from(u128::MAX) << BigInt::from(64);
+        assert_eq!(BigInt::from(5) >> huge.clone(), BigInt::from(0));
+        assert_eq!(BigInt::from(u128::MAX) >> huge, BigInt::from(0));
+    }
+
+    #[test]
+    fn test_checked_shl() {
+        assert_eq!(
+            BigInt::from(3).checked_shl(BigInt::from(2)),
+            Some(BigInt::from(12))
+        );
+        let big = BigInt::from(-3).checked_shl(BigInt::from(130)).unwrap();
+        assert_eq!(
+            Some(big.clone()),
+            BigInt::from(-3i128 << 100).checked_shl(BigInt::from(30))
+        );
+        assert_eq!(big >> BigInt::from(130), BigInt::from(-3));
+        assert_eq!(BigInt::from(3).checked_shl(BigInt::from(-1)), None);
+        let huge = BigInt::from(u128::MAX) << BigInt::from(64);
+        assert_eq!(BigInt::from(3).checked_shl(huge), None);
+    }
+
     #[test]
     fn test_pow() {
         assert_eq!(BigInt::from(1).pow(BigInt::from(2)), BigInt::from(1));
@@ -859,6 +2531,12 @@ mod tests {
 
         assert_ne!(a, 1i32);
         assert_ne!(b, 0i32);
+
+        assert_eq!(5i32, BigInt::from(5));
+        assert_eq!(5u8, BigInt::from(5));
+        assert_ne!(-5i64, BigInt::from(5));
+        assert_eq!(i8::MIN, BigInt::from(-128));
+        assert_eq!(u128::MAX, BigInt::from(u128::MAX));
     }
 
     #[test]
@@ -879,5 +2557,19 @@ mod tests {
         assert!(b > 0);
 
         assert!(c < 0);
+
+        let d = BigInt::from_limbs(&[1, 2], false);
+        let e = BigInt::from_limbs(&[2, 1], false);
+        assert!(d > e);
+        assert!(-d.clone() < -e.clone());
+
+        let three = BigInt::from(3);
+        let neg_three = BigInt::from(-3);
+        assert!(1i32 < three);
+        assert!(5u64 > three);
+        assert!(-5i8 < neg_three);
+        assert!(0u8 > neg_three);
+        assert!(3u8 >= three);
+        assert!(u64::MAX < d);
     }
 }
@@ -38,6 +38,22 @@ macro_rules! impl_ops_for_int {
     };
 }
 
+macro_rules! impl_reverse_cmp_for_int {
+    ($ty:ty) => {
+        impl PartialEq<BigInt> for $ty {
+            fn eq(&self, other: &BigInt) -> bool {
+                other == self
+            }
+        }
+
+        impl PartialOrd<BigInt> for $ty {
+            fn partial_cmp(&self, other: &BigInt) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+
 macro_rules! impl_for_int {
     ($signed:ty, $unsigned:ty) => {
         // From/TryFrom
@@ -164,11 +180,11 @@ macro_rules! impl_for_int {
                 if self.is_negative() != other.is_negative() {
                     return false;
                 }
-                let other = other.abs();
+                let other = other.unsigned_abs();
 
                 self.with_slice(|this| {
-                    let arr = int_to_arr::<_, _, { arr_size::<$unsigned>() }>(other as $unsigned);
-                    this == IntSlice::shrink(&arr as &[_])
+                    let arr = int_to_arr::<$unsigned, usize, { arr_size::<$unsigned>() }>(other);
+                    this == IntSlice::shrink(&arr as &[usize])
                 })
             }
         }
@@ -176,8 +192,8 @@ macro_rules! impl_for_int {
         impl PartialEq<$unsigned> for BigInt {
             fn eq(&self, other: &$unsigned) -> bool {
                 self.with_slice(|this| {
-                    let arr = int_to_arr::<_, _, { arr_size::<$unsigned>() }>(*other);
-                    this == IntSlice::shrink(&arr as &[_])
+                    let arr = int_to_arr::<$unsigned, usize, { arr_size::<$unsigned>() }>(*other);
+                    this == IntSlice::shrink(&arr as &[usize])
                 })
             }
         }
@@ -194,6 +210,9 @@ macro_rules! impl_for_int {
             }
         }
 
+        impl_reverse_cmp_for_int!($signed);
+        impl_reverse_cmp_for_int!($unsigned);
+
         // Operations
 
         impl_ops_for_int!($signed);
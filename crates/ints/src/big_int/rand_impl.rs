@@ -0,0 +1,131 @@
+use super::BigInt;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use numeric_traits::cast::FromTruncating;
+use numeric_traits::identity::One;
+use rand::distr::uniform::{Error, SampleBorrow, SampleUniform, UniformSampler};
+use rand::Rng;
+
+/// A sampler for uniformly distributed [`BigInt`] values within a range. This generates random
+/// limbs of the same bit length as the range, and rejects any that fall outside it, so there is no
+/// bias towards any part of the range.
+///
+/// This is usually used through [`Rng::random_range`], but can also be constructed directly to
+/// sample repeatedly from the same range.
+#[derive(Clone, Debug)]
+pub struct UniformBigInt {
+    low: BigInt,
+    range: Vec<usize>,
+}
+
+impl UniformBigInt {
+    fn from_range(low: BigInt, range: BigInt) -> UniformBigInt {
+        let range = range.with_limbs(|limbs| limbs.to_vec());
+        UniformBigInt { low, range }
+    }
+
+    fn sample_limbs<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<usize> {
+        let top = *self.range.last().unwrap();
+        let top_bits = usize::BITS - top.leading_zeros();
+        let top_mask = usize::MAX >> (usize::BITS - top_bits);
+
+        loop {
+            let mut limbs = (0..self.range.len())
+                .map(|_| usize::truncate_from(rng.next_u64()))
+                .collect::<Vec<_>>();
+            *limbs.last_mut().unwrap() &= top_mask;
+
+            // Compare from the most significant limb, both have the same length
+            if limbs.iter().rev().cmp(self.range.iter().rev()) == Ordering::Less {
+                return limbs;
+            }
+        }
+    }
+}
+
+impl UniformSampler for UniformBigInt {
+    type X = BigInt;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        if low >= high {
+            return Err(Error::EmptyRange);
+        }
+        Ok(UniformBigInt::from_range(low.clone(), high - low))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (low.borrow(), high.borrow());
+        if low > high {
+            return Err(Error::EmptyRange);
+        }
+        Ok(UniformBigInt::from_range(
+            low.clone(),
+            high - low + BigInt::one(),
+        ))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        let offset = BigInt::from_limbs(&self.sample_limbs(rng), false);
+        &self.low + offset
+    }
+}
+
+impl SampleUniform for BigInt {
+    type Sampler = UniformBigInt;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_small_range() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let low = BigInt::from(-3);
+        let high = BigInt::from(4);
+
+        let mut seen = [false; 7];
+        for _ in 0..1000 {
+            let val = rng.random_range(low.clone()..high.clone());
+            assert!(val >= low && val < high);
+            let idx = usize::try_from(val + 3).unwrap();
+            seen[idx] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn test_large_range() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let low = BigInt::from(u128::MAX) - BigInt::from(4);
+        let high = BigInt::from(u128::MAX) + BigInt::from(4);
+
+        let mut seen_low = false;
+        let mut seen_high = false;
+        for _ in 0..1000 {
+            let val = rng.random_range(low.clone()..=high.clone());
+            assert!(val >= low && val <= high);
+            seen_low |= val == low;
+            seen_high |= val == high;
+        }
+        assert!(seen_low && seen_high);
+    }
+
+    #[test]
+    fn test_empty_range() {
+        let val = BigInt::from(5);
+        assert!(UniformBigInt::new(&val, &val).is_err());
+        assert!(UniformBigInt::new_inclusive(&val, &val).is_ok());
+    }
+}
@@ -0,0 +1,288 @@
+//! Big integers stored in a caller-provided interner, rather than the global one
+
+use super::{add_limbs, div_limbs, BigInt, MaybeInline, Tag, TaggedOffset};
+use alloc::boxed::Box;
+use core::borrow::Borrow;
+use core::{fmt, ops, ptr};
+use numeric_bits::algos::ElementMul;
+use numeric_bits::utils::IntSlice;
+use numeric_traits::class::Signed;
+use numeric_utils::intern::InternId;
+use numeric_utils::Interner;
+
+/// The interner type backing a [`BigIntIn`]
+pub type BigIntStore = Interner<Box<[usize]>>;
+
+/// A 'big' integer like [`BigInt`], but with any large values stored in a borrowed
+/// [`BigIntStore`] instead of the global one. This allows a batch of computations to use a local
+/// store, which is freed wholesale once it is dropped.
+///
+/// Values from different stores can't be mixed - doing arithmetic on them will panic.
+pub struct BigIntIn<'a> {
+    val: TaggedOffset,
+    store: &'a BigIntStore,
+}
+
+impl<'a> BigIntIn<'a> {
+    fn val(&self) -> MaybeInline<'_> {
+        if self.val.tag().inline() {
+            MaybeInline::Inline(self.val.offset())
+        } else {
+            MaybeInline::Slice(self.store.get(InternId::from_usize(self.val.offset())))
+        }
+    }
+
+    fn same_store(&self, other: &BigIntIn<'_>) -> bool {
+        ptr::eq(self.store, other.store)
+    }
+
+    fn new_slice<V>(val: V, neg: bool, store: &'a BigIntStore) -> BigIntIn<'a>
+    where
+        V: IntSlice<usize> + Borrow<[usize]> + Into<Box<[usize]>>,
+    {
+        let val = IntSlice::shrink(val);
+        let val = if val.len() == 1 && val[0] <= (usize::MAX >> 2) {
+            let tag = if val[0] != 0 && neg {
+                Tag::InlineNeg
+            } else {
+                Tag::Inline
+            };
+            TaggedOffset::new(val[0], tag)
+        } else {
+            let offset = store.add::<_, [usize]>(val);
+            TaggedOffset::new(offset.into_usize(), if neg { Tag::Neg } else { Tag::None })
+        };
+        BigIntIn { val, store }
+    }
+
+    /// Create a new value of zero, associated with the provided store
+    #[must_use]
+    pub const fn new_in(store: &'a BigIntStore) -> BigIntIn<'a> {
+        BigIntIn {
+            val: TaggedOffset::new(0, Tag::Inline),
+            store,
+        }
+    }
+
+    /// Create a new value from a slice of `usize` limbs and a sign, in the provided store. This
+    /// follows the same rules as [`BigInt::from_limbs`].
+    #[must_use]
+    pub fn from_limbs_in(limbs: &[usize], negative: bool, store: &'a BigIntStore) -> BigIntIn<'a> {
+        if limbs.is_empty() {
+            BigIntIn::new_in(store)
+        } else {
+            BigIntIn::new_slice(limbs, negative, store)
+        }
+    }
+
+    /// Copy a global [`BigInt`] into the provided store
+    #[must_use]
+    pub fn from_big_int(val: &BigInt, store: &'a BigIntStore) -> BigIntIn<'a> {
+        val.with_limbs(|limbs| BigIntIn::from_limbs_in(limbs, val.is_negative(), store))
+    }
+
+    /// Copy this value into a [`BigInt`] in the global store
+    #[must_use]
+    pub fn to_big_int(&self) -> BigInt {
+        self.with_limbs(|limbs| BigInt::from_limbs(limbs, self.is_negative()))
+    }
+
+    /// Call a function with the magnitude of this value, as a slice of `usize` limbs. This
+    /// follows the same rules as [`BigInt::with_limbs`].
+    pub fn with_limbs<R>(&self, f: impl FnOnce(&[usize]) -> R) -> R {
+        f(self.val().slice())
+    }
+
+    /// Whether this value is less than zero
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.val.tag().negative()
+    }
+
+    /// Check whether this value is stored in its interner, rather than inline
+    #[must_use]
+    pub const fn is_interned(&self) -> bool {
+        !self.val.tag().inline()
+    }
+
+    /// Get the store this value lives in
+    #[must_use]
+    pub const fn store(&self) -> &'a BigIntStore {
+        self.store
+    }
+
+    fn with_slices<R>(
+        left: &BigIntIn<'_>,
+        right: &BigIntIn<'_>,
+        f: impl FnOnce(&[usize], &[usize]) -> R,
+    ) -> R {
+        assert!(
+            left.same_store(right),
+            "Can't combine values from different stores"
+        );
+        left.with_limbs(|left| right.with_limbs(|right| f(left, right)))
+    }
+}
+
+impl Clone for BigIntIn<'_> {
+    fn clone(&self) -> Self {
+        if self.is_interned() {
+            self.store.incr(InternId::from_usize(self.val.offset()));
+        }
+        BigIntIn {
+            val: self.val,
+            store: self.store,
+        }
+    }
+}
+
+impl Drop for BigIntIn<'_> {
+    fn drop(&mut self) {
+        if self.is_interned() {
+            self.store.decr(InternId::from_usize(self.val.offset()));
+        }
+    }
+}
+
+impl PartialEq for BigIntIn<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_negative() == other.is_negative()
+            && self.with_limbs(|this| other.with_limbs(|other| this == other))
+    }
+}
+
+impl Eq for BigIntIn<'_> {}
+
+impl fmt::Debug for BigIntIn<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.with_limbs(|limbs| {
+            f.debug_struct("BigIntIn")
+                .field("negative", &self.is_negative())
+                .field("limbs", &limbs)
+                .finish()
+        })
+    }
+}
+
+macro_rules! impl_scoped_op {
+    ($trait:ident, $meth:ident, |$this:ident, $this_neg:ident, $other:ident, $other_neg:ident| $block:block) => {
+        impl<'a> ops::$trait<&BigIntIn<'a>> for &BigIntIn<'a> {
+            type Output = BigIntIn<'a>;
+
+            fn $meth(self, rhs: &BigIntIn<'a>) -> BigIntIn<'a> {
+                let ($this_neg, $other_neg) = (self.is_negative(), rhs.is_negative());
+                let (out, neg) = BigIntIn::with_slices(self, rhs, |$this, $other| $block);
+                BigIntIn::new_slice(out, neg, self.store)
+            }
+        }
+
+        impl<'a> ops::$trait for BigIntIn<'a> {
+            type Output = BigIntIn<'a>;
+
+            fn $meth(self, rhs: BigIntIn<'a>) -> BigIntIn<'a> {
+                ops::$trait::$meth(&self, &rhs)
+            }
+        }
+    };
+}
+
+impl_scoped_op!(Add, add, |this, this_neg, other, other_neg| {
+    add_limbs(this, this_neg, other, other_neg)
+});
+
+impl_scoped_op!(Sub, sub, |this, this_neg, other, other_neg| {
+    add_limbs(this, this_neg, other, !other_neg)
+});
+
+impl_scoped_op!(Mul, mul, |this, this_neg, other, other_neg| {
+    (ElementMul::mul(this, other), this_neg != other_neg)
+});
+
+impl_scoped_op!(Div, div, |this, this_neg, other, other_neg| {
+    div_limbs(this, this_neg, other, other_neg).0
+});
+
+impl_scoped_op!(Rem, rem, |this, this_neg, other, other_neg| {
+    div_limbs(this, this_neg, other, other_neg).1
+});
+
+impl<'a> ops::Neg for BigIntIn<'a> {
+    type Output = BigIntIn<'a>;
+
+    fn neg(mut self) -> BigIntIn<'a> {
+        // Zero is never negative
+        if self.with_limbs(|limbs| limbs != [0]) {
+            self.val = self.val.invert_neg();
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big<'a>(store: &'a BigIntStore, limbs: &[usize]) -> BigIntIn<'a> {
+        BigIntIn::from_limbs_in(limbs, false, store)
+    }
+
+    #[test]
+    fn test_inline() {
+        let store = BigIntStore::new();
+        let val = BigIntIn::from_big_int(&BigInt::from(12), &store);
+        assert!(!val.is_interned());
+        assert_eq!(val.to_big_int(), 12);
+        assert_eq!((-val).to_big_int(), -12);
+        assert_eq!((-BigIntIn::new_in(&store)).to_big_int(), 0);
+    }
+
+    #[test]
+    fn test_ops() {
+        let store = BigIntStore::new();
+        let max = big(&store, &[usize::MAX]);
+        let one = big(&store, &[1]);
+
+        let sum = &max + &one;
+        assert!(sum.is_interned());
+        assert_eq!(sum, big(&store, &[0, 1]));
+        assert_eq!(&sum - &one, max);
+        assert_eq!(&one - &sum, -max.clone());
+        assert_eq!(&sum * &sum, big(&store, &[0, 0, 1]));
+        assert_eq!(&(&sum * &sum) / &sum, sum);
+        assert_eq!(&(&sum + &one) % &sum, one);
+
+        let expected = (BigInt::from(usize::MAX) + 1) * -3;
+        let three = BigIntIn::from_big_int(&BigInt::from(-3), &store);
+        assert_eq!((sum * three).to_big_int(), expected);
+    }
+
+    #[test]
+    fn test_independent_stores() {
+        let store1 = BigIntStore::new();
+        let store2 = BigIntStore::new();
+
+        let a = big(&store1, &[1, 2, 3]);
+        let b = big(&store2, &[4, 5, 6]);
+        // Both are the first value in their store, so share an id, but not a value
+        assert_eq!(a.val, b.val);
+        assert_eq!(a.to_big_int(), BigInt::from_limbs(&[1, 2, 3], false));
+        assert_eq!(b.to_big_int(), BigInt::from_limbs(&[4, 5, 6], false));
+
+        let id = InternId::from_usize(a.val.offset());
+        let a2 = a.clone();
+        assert_eq!(store1.refcount(id.clone()), 2);
+        assert_eq!(store2.refcount(id.clone()), 1);
+        drop(a);
+        drop(a2);
+        assert!(store1.try_get(id.clone()).is_none());
+        assert_eq!(&**store2.get(id), &[4, 5, 6]);
+    }
+
+    #[test]
+    #[should_panic = "Can't combine values from different stores"]
+    fn test_mixed_stores() {
+        let store1 = BigIntStore::new();
+        let store2 = BigIntStore::new();
+        let _ = big(&store1, &[1, 2]) + big(&store2, &[1, 2]);
+    }
+}
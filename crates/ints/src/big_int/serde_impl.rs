@@ -0,0 +1,177 @@
+use super::BigInt;
+use alloc::vec::Vec;
+use core::fmt;
+use numeric_traits::cast::FromStrRadix;
+use numeric_traits::class::Signed;
+use serde::de::{Error, SeqAccess, Unexpected, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+const LIMB_SIZE: usize = size_of::<usize>();
+
+/// The magnitude of a value as little-endian bytes, with no trailing zeroes
+struct MagnitudeBytes(Vec<u8>);
+
+impl Serialize for MagnitudeBytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for MagnitudeBytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BytesVisitor;
+
+        impl<'de> Visitor<'de> for BytesVisitor {
+            type Value = MagnitudeBytes;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("little-endian magnitude bytes")
+            }
+
+            fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<MagnitudeBytes, E> {
+                Ok(MagnitudeBytes(v.to_vec()))
+            }
+
+            fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<MagnitudeBytes, E> {
+                Ok(MagnitudeBytes(v))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<MagnitudeBytes, A::Error> {
+                let mut out = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(b) = seq.next_element()? {
+                    out.push(b);
+                }
+                Ok(MagnitudeBytes(out))
+            }
+        }
+
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+}
+
+/// Human-readable formats serialize as a decimal string, such as `"-1234"`. Other formats
+/// serialize as a tuple of the sign, `true` if negative, and the magnitude as little-endian bytes.
+/// The byte form doesn't depend on the width of `usize`.
+impl Serialize for BigInt {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            let mut bytes = self.with_slice(|limbs| {
+                limbs
+                    .iter()
+                    .flat_map(|limb| limb.to_le_bytes())
+                    .collect::<Vec<_>>()
+            });
+            while bytes.last() == Some(&0) {
+                bytes.pop();
+            }
+
+            let mut tuple = serializer.serialize_tuple(2)?;
+            tuple.serialize_element(&self.is_negative())?;
+            tuple.serialize_element(&MagnitudeBytes(bytes))?;
+            tuple.end()
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BigInt {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct StrVisitor;
+
+        impl Visitor<'_> for StrVisitor {
+            type Value = BigInt;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a decimal integer string")
+            }
+
+            fn visit_str<E: Error>(self, v: &str) -> Result<BigInt, E> {
+                if v.is_empty() {
+                    return Err(E::invalid_value(Unexpected::Str(v), &self));
+                }
+                BigInt::from_str_radix(v, 10)
+                    .map_err(|_| E::invalid_value(Unexpected::Str(v), &self))
+            }
+        }
+
+        struct TupleVisitor;
+
+        impl<'de> Visitor<'de> for TupleVisitor {
+            type Value = BigInt;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sign and magnitude bytes")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<BigInt, A::Error> {
+                let neg: bool = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+                let MagnitudeBytes(bytes) = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+
+                let limbs = bytes
+                    .chunks(LIMB_SIZE)
+                    .map(|chunk| {
+                        let mut limb = [0; LIMB_SIZE];
+                        limb[..chunk.len()].copy_from_slice(chunk);
+                        usize::from_le_bytes(limb)
+                    })
+                    .collect::<Vec<_>>();
+                Ok(BigInt::from_limbs(&limbs, neg))
+            }
+        }
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(StrVisitor)
+        } else {
+            deserializer.deserialize_tuple(2, TupleVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> [BigInt; 6] {
+        [
+            BigInt::from(0),
+            BigInt::from(1),
+            BigInt::from(-1234),
+            BigInt::from(u128::MAX),
+            -(BigInt::from(u128::MAX) << BigInt::from(100)),
+            BigInt::from_limbs(&[0, 0, 1, 0, 7], false),
+        ]
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            serde_json::to_string(&BigInt::from(-1234)).unwrap(),
+            "\"-1234\""
+        );
+        for val in values() {
+            let json = serde_json::to_string(&val).unwrap();
+            assert_eq!(serde_json::from_str::<BigInt>(&json).unwrap(), val);
+        }
+
+        assert!(serde_json::from_str::<BigInt>("\"\"").is_err());
+        assert!(serde_json::from_str::<BigInt>("\"12a\"").is_err());
+        assert!(serde_json::from_str::<BigInt>("12").is_err());
+    }
+
+    #[test]
+    fn test_binary() {
+        let bytes = bincode::serialize(&BigInt::from(-0x1234)).unwrap();
+        // Sign, then a length-prefixed byte string
+        assert_eq!(bytes, [1, 2, 0, 0, 0, 0, 0, 0, 0, 0x34, 0x12]);
+        for val in values() {
+            let bytes = bincode::serialize(&val).unwrap();
+            assert_eq!(bincode::deserialize::<BigInt>(&bytes).unwrap(), val);
+        }
+    }
+}
@@ -2,26 +2,40 @@
 
 #![allow(unused_variables)]
 
-use core::array;
+use crate::u::{TryFromSliceError, LOWER_DIGITS, UPPER_DIGITS};
+use crate::U;
 use core::cmp::Ordering;
 use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Neg, Not, Rem, Shl, Shr, Sub};
-use numeric_bits::algos::{ElementAdd, ElementSub};
+use core::str::FromStr;
+use core::{array, fmt};
+use numeric_bits::algos::{ElementAdd, ElementCmp, ElementMul, ElementSub};
+use numeric_bits::utils::const_reverse;
 use numeric_static_iter::{IntoStaticIter, StaticIter};
+use numeric_traits::bytes::ConvertBytes;
 use numeric_traits::class::{Bounded, BoundedSigned, Integral, Numeric, Signed};
 use numeric_traits::identity::{One, Zero};
 use numeric_traits::ops::checked::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
 use numeric_traits::ops::saturating::{SaturatingAdd, SaturatingMul, SaturatingSub};
-use numeric_traits::ops::Pow;
+use numeric_traits::ops::widening::WideningMul;
+use numeric_traits::ops::wrapping::WrappingNeg;
+use numeric_traits::ops::{Gcd, Lcm, Pow};
 use numeric_utils::{static_assert, static_assert_traits};
 
 #[cfg(feature = "rand")]
 mod rand_impl;
+#[cfg(feature = "serde")]
+mod serde_impl;
 
-// TODO: Manual debug that prints the value
 /// N-byte bounded, signed integer. `I<1> == i8`, `I<16> == i128`, etc.
 ///
 /// Represented in two's complement, with the highest bit forming the sign bit
-#[derive(Debug)]
+///
+/// `N` must be at least 1 - a zero-width integer fails to compile:
+///
+/// ```compile_fail
+/// # use numeric_ints::I;
+/// let _ = I::<0>::new();
+/// ```
 pub struct I<const N: usize>([u8; N]);
 
 static_assert!(size_of::<I<2>>() == 2);
@@ -29,6 +43,170 @@ static_assert!(size_of::<I<4>>() == 4);
 static_assert!(size_of::<I<8>>() == 8);
 static_assert_traits!(I<4>: Send + Sync);
 
+impl<const N: usize> I<N> {
+    /// Evaluated by every constructor, so that naming a zero-width integer is a compile error
+    /// rather than a panic the first time its bytes are indexed. Anything creating a value from
+    /// new bytes, such as random sampling, must go through a constructor. Only operations on an
+    /// existing value, which has already passed this check, may build `I` directly.
+    const NONZERO_WIDTH: () = assert!(N > 0, "I<0> is not a valid integer width");
+
+    /// The smallest value that can be represented by this integer type, `-2^(BITS - 1)`
+    pub const MIN: I<N> = {
+        let mut bytes = [0; N];
+        bytes[N - 1] = 0x80;
+        I::from_le_bytes(bytes)
+    };
+
+    /// The largest value that can be represented by this integer type, `2^(BITS - 1) - 1`
+    pub const MAX: I<N> = {
+        let mut bytes = [0xFF; N];
+        bytes[N - 1] = 0x7F;
+        I::from_le_bytes(bytes)
+    };
+
+    /// The size of this integer type in bits
+    pub const BITS: u32 = (N * 8) as u32;
+
+    /// Create a new instance containing the default value (0)
+    #[inline]
+    #[must_use]
+    pub const fn new() -> I<N> {
+        let () = Self::NONZERO_WIDTH;
+        I([0; N])
+    }
+
+    /// Create a value from raw bytes, laid out in little-endian order
+    #[must_use]
+    pub const fn from_le_bytes(bytes: [u8; N]) -> I<N> {
+        let () = Self::NONZERO_WIDTH;
+        I(bytes)
+    }
+
+    /// Create a value from raw bytes, laid out in big-endian order
+    #[must_use]
+    pub const fn from_be_bytes(bytes: [u8; N]) -> I<N> {
+        let () = Self::NONZERO_WIDTH;
+        I(const_reverse(bytes))
+    }
+
+    /// Create a value from raw bytes, laid out in the native endianness
+    #[must_use]
+    pub const fn from_ne_bytes(bytes: [u8; N]) -> I<N> {
+        if cfg!(target_endian = "little") {
+            Self::from_le_bytes(bytes)
+        } else {
+            Self::from_be_bytes(bytes)
+        }
+    }
+
+    /// Create a value from a slice of bytes of any length, laid out in little-endian order.
+    /// Shorter slices are sign-extended.
+    ///
+    /// # Errors
+    ///
+    /// If the slice is longer than `N` bytes and the extra high bytes aren't a sign-extension of
+    /// the resulting value
+    pub fn try_from_le_slice(bytes: &[u8]) -> Result<I<N>, TryFromSliceError> {
+        let () = Self::NONZERO_WIDTH;
+        let negative = bytes.last().is_some_and(|&b| b & 0x80 != 0);
+        let fill = if negative { 0xFF } else { 0 };
+
+        let mut out = [fill; N];
+        let len = bytes.len().min(N);
+        out[..len].copy_from_slice(&bytes[..len]);
+        let out = I(out);
+
+        if bytes.len() > N
+            && (out.is_negative() != negative || bytes[N..].iter().any(|&b| b != fill))
+        {
+            Err(TryFromSliceError::new())
+        } else {
+            Ok(out)
+        }
+    }
+
+    /// Convert this value to raw bytes, laid out in little-endian order
+    #[must_use]
+    pub const fn to_le_bytes(self) -> [u8; N] {
+        self.0
+    }
+
+    /// Convert this value to raw bytes, laid out in big-endian order
+    #[must_use]
+    pub const fn to_be_bytes(self) -> [u8; N] {
+        const_reverse(self.0)
+    }
+
+    /// Convert this value to raw bytes, laid out in the native endianness
+    #[must_use]
+    pub const fn to_ne_bytes(self) -> [u8; N] {
+        if cfg!(target_endian = "little") {
+            self.to_le_bytes()
+        } else {
+            self.to_be_bytes()
+        }
+    }
+
+    /// Get the absolute value of this value as the unsigned type of the same width. Unlike
+    /// [`Signed::abs`], this can't overflow, as the magnitude of [`I::MIN`] fits in a `U<N>`.
+    #[must_use]
+    pub fn unsigned_abs(self) -> U<N> {
+        if self.is_negative() {
+            U::from_le_bytes((-self).0)
+        } else {
+            U::from_le_bytes(self.0)
+        }
+    }
+
+    /// Get the number of significant bits in the magnitude of this value, ignoring the sign. This
+    /// is [`U::magnitude_bits`] of [`I::unsigned_abs`].
+    #[must_use]
+    pub fn magnitude_bits(&self) -> u32 {
+        self.unsigned_abs().magnitude_bits()
+    }
+
+    /// Calculate the quotient of Euclidean division of this value by `rhs`. This is the value `q`
+    /// such that `self = q * rhs + r`, where `r` is [`I::rem_euclid`], and `0 <= r < |rhs|`.
+    ///
+    /// Like the primitive integers' `wrapping_div_euclid`, `I::MIN.div_euclid(-1)` overflows, as
+    /// the quotient `2^(BITS - 1)` doesn't fit, and wraps back to [`I::MIN`].
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is zero
+    #[must_use]
+    pub fn div_euclid(self, rhs: I<N>) -> I<N> {
+        let quot = self / rhs;
+        if (self % rhs).is_negative() {
+            if rhs.is_negative() {
+                quot + I::one()
+            } else {
+                quot - I::one()
+            }
+        } else {
+            quot
+        }
+    }
+
+    /// Calculate the remainder of Euclidean division of this value by `rhs`. Unlike the `%`
+    /// operator, whose result takes the sign of the dividend, this is never negative.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is zero
+    #[must_use]
+    pub fn rem_euclid(self, rhs: I<N>) -> I<N> {
+        let rem = self % rhs;
+        if !rem.is_negative() {
+            rem
+        } else if rhs.is_negative() {
+            rem - rhs
+        } else {
+            rem + rhs
+        }
+    }
+}
+
 impl<const N: usize> Copy for I<N> {}
 
 impl<const N: usize> Clone for I<N> {
@@ -37,6 +215,13 @@ impl<const N: usize> Clone for I<N> {
     }
 }
 
+impl<const N: usize> Default for I<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<const N: usize> Add for I<N> {
     type Output = Self;
 
@@ -58,8 +243,10 @@ impl<const N: usize> Sub for I<N> {
 impl<const N: usize> Mul for I<N> {
     type Output = Self;
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        todo!()
+    fn mul(mut self, rhs: Self) -> Self::Output {
+        // Two's complement multiplication is identical to unsigned multiplication, when wrapping
+        ElementMul::mul_wrapping(&mut self.0, &rhs.0);
+        self
     }
 }
 
@@ -67,7 +254,12 @@ impl<const N: usize> Div for I<N> {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
-        todo!()
+        let out = I((self.unsigned_abs() / rhs.unsigned_abs()).to_le_bytes());
+        if self.is_negative() != rhs.is_negative() {
+            -out
+        } else {
+            out
+        }
     }
 }
 
@@ -75,7 +267,109 @@ impl<const N: usize> Rem for I<N> {
     type Output = Self;
 
     fn rem(self, rhs: Self) -> Self::Output {
-        todo!()
+        // The remainder takes the sign of the dividend, matching the primitive integers
+        let out = I((self.unsigned_abs() % rhs.unsigned_abs()).to_le_bytes());
+        if self.is_negative() {
+            -out
+        } else {
+            out
+        }
+    }
+}
+
+impl<const N: usize> fmt::Debug for I<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const N: usize> fmt::Display for I<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.unsigned_abs()
+            .fmt_base(f, !self.is_negative(), 10, "", LOWER_DIGITS)
+    }
+}
+
+// Like the primitive signed integers, non-decimal formats show the two's complement bits
+
+impl<const N: usize> fmt::Binary for I<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        U::from_le_bytes(self.0).fmt_base(f, true, 2, "0b", LOWER_DIGITS)
+    }
+}
+
+impl<const N: usize> fmt::Octal for I<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        U::from_le_bytes(self.0).fmt_base(f, true, 8, "0o", LOWER_DIGITS)
+    }
+}
+
+impl<const N: usize> fmt::LowerHex for I<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        U::from_le_bytes(self.0).fmt_base(f, true, 16, "0x", LOWER_DIGITS)
+    }
+}
+
+impl<const N: usize> fmt::UpperHex for I<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        U::from_le_bytes(self.0).fmt_base(f, true, 16, "0x", UPPER_DIGITS)
+    }
+}
+
+/// The error for when you try to parse a bounded integer from a string that isn't a valid decimal
+/// number, or holds a value outside the range of the type being created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseIntError {
+    /// The string contained no digits
+    Empty,
+    /// Character wasn't a decimal digit, along with its byte index in the input string
+    InvalidChar(char, usize),
+    /// The value was outside the range of the type
+    Overflow,
+}
+
+impl<const N: usize> FromStr for I<N> {
+    type Err = ParseIntError;
+
+    /// Parse a decimal string with an optional leading `+` or `-`, such as `-128`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (neg, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        if digits.is_empty() {
+            return Err(ParseIntError::Empty);
+        }
+
+        let digit = |d: u8| {
+            let mut bytes = [0; N];
+            bytes[0] = d;
+            U::from_le_bytes(bytes)
+        };
+        let ten = digit(10);
+        let mut mag = U::<N>::new();
+        let start = s.len() - digits.len();
+        for (idx, c) in digits.char_indices() {
+            let d = c
+                .to_digit(10)
+                .ok_or(ParseIntError::InvalidChar(c, start + idx))?;
+            mag = mag
+                .checked_mul(ten)
+                .and_then(|mag| mag.checked_add(digit(d as u8)))
+                .ok_or(ParseIntError::Overflow)?;
+        }
+
+        // The magnitude of the minimum has only the sign bit set, and so can't be reached by
+        // negating a positive value. Taking the two's complement of the magnitude directly handles
+        // it like any other negative value.
+        let min_mag = U::from_le_bytes(Self::min_value().0);
+        if mag > min_mag || (!neg && mag == min_mag) {
+            Err(ParseIntError::Overflow)
+        } else if neg {
+            Ok(I(mag.wrapping_neg().to_le_bytes()))
+        } else {
+            Ok(I(mag.to_le_bytes()))
+        }
     }
 }
 
@@ -83,7 +377,9 @@ impl<const N: usize> Neg for I<N> {
     type Output = Self;
 
     fn neg(self) -> Self::Output {
-        todo!()
+        let mut out = !self;
+        ElementAdd::add_wrapping(&mut out.0, &[1]);
+        out
     }
 }
 
@@ -137,11 +433,14 @@ impl<const N: usize> BitXor for I<N> {
     }
 }
 
+// Shift amounts follow the same rules as `U<N>`. A negative amount is treated as its two's
+// complement bit pattern, so it's always out of range, as with the primitive integers.
+
 impl<const N: usize> Shl for I<N> {
     type Output = Self;
 
     fn shl(self, rhs: Self) -> Self::Output {
-        todo!()
+        self << U::from_le_bytes(rhs.0).shift_amount()
     }
 }
 
@@ -149,7 +448,7 @@ impl<const N: usize> Shr for I<N> {
     type Output = Self;
 
     fn shr(self, rhs: Self) -> Self::Output {
-        todo!()
+        self >> U::from_le_bytes(rhs.0).shift_amount()
     }
 }
 
@@ -157,25 +456,63 @@ impl<const N: usize> Shl<usize> for I<N> {
     type Output = Self;
 
     fn shl(self, rhs: usize) -> Self::Output {
-        todo!()
+        I((U::from_le_bytes(self.0) << rhs).to_le_bytes())
     }
 }
 
 impl<const N: usize> Shr<usize> for I<N> {
     type Output = Self;
 
+    /// An arithmetic shift, filling the high bits with copies of the sign bit
     fn shr(self, rhs: usize) -> Self::Output {
-        todo!()
+        let val = U::from_le_bytes(self.0);
+        if self.is_negative() {
+            I((!(!val >> rhs)).to_le_bytes())
+        } else {
+            I((val >> rhs).to_le_bytes())
+        }
+    }
+}
+
+impl<const N: usize> ConvertBytes<N> for I<N> {
+    #[inline]
+    fn from_ne_bytes(bytes: [u8; N]) -> Self {
+        I::from_ne_bytes(bytes)
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: [u8; N]) -> Self {
+        I::from_be_bytes(bytes)
+    }
+
+    #[inline]
+    fn from_le_bytes(bytes: [u8; N]) -> Self {
+        I::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    fn to_ne_bytes(self) -> [u8; N] {
+        I::to_ne_bytes(self)
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> [u8; N] {
+        I::to_be_bytes(self)
+    }
+
+    #[inline]
+    fn to_le_bytes(self) -> [u8; N] {
+        I::to_le_bytes(self)
     }
 }
 
 impl<const N: usize> Bounded for I<N> {
     fn min_value() -> Self {
-        I(array::from_fn(|idx| if idx == N - 1 { 0x80 } else { 0 }))
+        I::MIN
     }
 
     fn max_value() -> Self {
-        I(array::from_fn(|idx| if idx == N - 1 { 0x7F } else { 0xFF }))
+        I::MAX
     }
 }
 
@@ -186,7 +523,7 @@ impl<const N: usize> BoundedSigned for I<N> {
 
     fn max_negative() -> Self {
         // TODO: Maybe -I::one()
-        I([0xFF; N])
+        I::from_le_bytes([0xFF; N])
     }
 }
 
@@ -206,7 +543,13 @@ impl<const N: usize> PartialOrd for I<N> {
 
 impl<const N: usize> Ord for I<N> {
     fn cmp(&self, other: &Self) -> Ordering {
-        todo!()
+        match (self.is_negative(), other.is_negative()) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            // With equal signs, two's complement values order the same as their unsigned bits.
+            // The bytes are little-endian, so they must be compared from the most significant end.
+            _ => ElementCmp::cmp(&self.0, &other.0),
+        }
     }
 }
 
@@ -242,6 +585,32 @@ impl<const N: usize> CheckedDiv for I<N> {
     }
 }
 
+impl<const N: usize> WideningMul for I<N> {
+    /// The high half holds the sign of the full result, while the low half holds its low bits
+    fn widening_mul(self, mul: Self, add: Self) -> (Self, Self) {
+        // The full result always fits in `2N` signed bytes, so sign-extend everything to that
+        // width, as a low and high half on the stack, and let the arithmetic wrap
+        let extend = |val: I<N>| {
+            let fill = if val.is_negative() { 0xFF } else { 0 };
+            [val.0, [fill; N]]
+        };
+
+        let mut wide = extend(self);
+        ElementMul::mul_wrapping(wide.as_flattened_mut(), extend(mul).as_flattened());
+        ElementAdd::add_wrapping(wide.as_flattened_mut(), extend(add).as_flattened());
+        let [low, high] = wide;
+        (I(low), I(high))
+    }
+}
+
+impl<const N: usize> WrappingNeg for I<N> {
+    type Output = Self;
+
+    fn wrapping_neg(self) -> Self::Output {
+        -self
+    }
+}
+
 impl<const N: usize> SaturatingAdd for I<N> {
     type Output = Self;
 
@@ -268,7 +637,7 @@ impl<const N: usize> SaturatingMul for I<N> {
 
 impl<const N: usize> Zero for I<N> {
     fn zero() -> Self {
-        I([0; N])
+        I::new()
     }
 
     fn is_zero(&self) -> bool {
@@ -278,7 +647,7 @@ impl<const N: usize> Zero for I<N> {
 
 impl<const N: usize> One for I<N> {
     fn one() -> Self {
-        I(array::from_fn(|idx| if idx == 0 { 1 } else { 0 }))
+        I::from_le_bytes(array::from_fn(|idx| if idx == 0 { 1 } else { 0 }))
     }
 
     fn is_one(&self) -> bool {
@@ -294,13 +663,36 @@ impl<const N: usize> Pow for I<N> {
     }
 }
 
+impl<const N: usize> Gcd for I<N> {
+    type Output = Self;
+
+    /// The greatest common divisor of the magnitudes, which is never negative except when it's
+    /// `2^(BITS - 1)`, as in `I::MIN.gcd(I::zero())`. That doesn't fit, and wraps back to
+    /// [`I::MIN`] - use [`Gcd::gcd`] on the [`I::unsigned_abs`] of each value to avoid this.
+    fn gcd(self, rhs: Self) -> Self::Output {
+        I(self.unsigned_abs().gcd(rhs.unsigned_abs()).to_le_bytes())
+    }
+}
+
+impl<const N: usize> Lcm for I<N> {
+    type Output = Self;
+
+    fn lcm(self, rhs: Self) -> Self::Output {
+        I(self.unsigned_abs().lcm(rhs.unsigned_abs()).to_le_bytes())
+    }
+}
+
 impl<const N: usize> Numeric for I<N> {}
 
 impl<const N: usize> Signed for I<N> {
-    fn abs(mut self) -> Self {
-        let last = self.0.last_mut().unwrap();
-        *last &= 0x7F;
-        self
+    /// Negates negative values. Like the primitive integers' `wrapping_abs`, the absolute value
+    /// of [`I::MIN`] doesn't fit, and wraps back to `MIN` - use [`I::unsigned_abs`] to avoid this.
+    fn abs(self) -> Self {
+        if self.is_negative() {
+            -self
+        } else {
+            self
+        }
     }
 
     fn is_positive(&self) -> bool {
@@ -319,6 +711,129 @@ impl<const N: usize> Integral for I<N> {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_convert_bytes() {
+        let val = I::<4>::from_le_bytes((-0x1234_5678i32).to_le_bytes());
+        let bytes = ConvertBytes::to_be_bytes(val);
+        assert_eq!(bytes, (-0x1234_5678i32).to_be_bytes());
+        assert_eq!(<I<4> as ConvertBytes<4>>::from_be_bytes(bytes), val);
+    }
+
+    #[test]
+    fn test_shift() {
+        let i8 = |val: i8| I::<1>::from_le_bytes(val.to_le_bytes());
+        for val in [i8::MIN, -100, -3, -1, 0, 1, 77, i8::MAX] {
+            for shift in 0..8 {
+                assert_eq!(i8(val) << shift, i8(val << shift), "{val} << {shift}");
+                assert_eq!(i8(val) >> shift, i8(val >> shift), "{val} >> {shift}");
+                assert_eq!(i8(val) << i8(shift as i8), i8(val << shift));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "attempt to shift left with overflow"]
+    fn test_shl_overflow() {
+        let _ = I::<1>::from_le_bytes([1]) << 8usize;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "attempt to shift right with overflow"]
+    fn test_shr_negative_amount() {
+        let _ = I::<1>::from_le_bytes([1]) >> I::from_le_bytes((-1i8).to_le_bytes());
+    }
+
+    #[test]
+    fn test_magnitude_bits() {
+        let i16 = |val: i16| I::<2>::from_le_bytes(val.to_le_bytes());
+        for val in [i16::MIN, -1000, -256, -1, 0, 1, 255, 1000, i16::MAX] {
+            assert_eq!(
+                i16(val).magnitude_bits(),
+                u16::BITS - val.unsigned_abs().leading_zeros(),
+                "{val}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ord() {
+        let i16 = |val: i16| I::<2>::from_le_bytes(val.to_le_bytes());
+        assert!(I::<2>::from_le_bytes([0, 1]) > I::<2>::from_le_bytes([1, 0]));
+        assert!(i16(256) > i16(1));
+        assert!(i16(-256) < i16(-1));
+        assert!(i16(-257) < i16(-256));
+        assert!(i16(-1) < i16(0));
+        assert_eq!(i16(-300).cmp(&i16(-300)), Ordering::Equal);
+
+        let vals = [
+            i16::MIN,
+            -300,
+            -256,
+            -255,
+            -1,
+            0,
+            1,
+            255,
+            256,
+            300,
+            i16::MAX,
+        ];
+        for a in vals {
+            for b in vals {
+                assert_eq!(i16(a).cmp(&i16(b)), a.cmp(&b), "{a} cmp {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_euclid() {
+        let i8 = |val: i8| I::<1>::from_le_bytes(val.to_le_bytes());
+        assert_eq!(i8(-7).rem_euclid(i8(3)), i8(2));
+        assert_eq!(i8(-7).div_euclid(i8(3)), i8(-3));
+
+        for lhs in [i8::MIN, -100, -7, -3, -1, 0, 1, 7, 77, i8::MAX] {
+            for rhs in [i8::MIN, -100, -3, -1, 1, 3, 7, i8::MAX] {
+                assert_eq!(
+                    i8(lhs).div_euclid(i8(rhs)),
+                    i8(lhs.wrapping_div_euclid(rhs)),
+                    "{lhs}.div_euclid({rhs})"
+                );
+                assert_eq!(
+                    i8(lhs).rem_euclid(i8(rhs)),
+                    i8(lhs.wrapping_rem_euclid(rhs)),
+                    "{lhs}.rem_euclid({rhs})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_abs() {
+        let i8 = |val: i8| I::<1>::from_le_bytes(val.to_le_bytes());
+        for val in [i8::MIN, -100, -3, -1, 0, 1, 77, i8::MAX] {
+            assert_eq!(i8(val).abs(), i8(val.wrapping_abs()));
+            assert_eq!(i8(val).unsigned_abs().as_u8(), val.unsigned_abs());
+        }
+        assert_eq!(I::<1>::MIN.unsigned_abs().as_u8(), 128);
+        assert_eq!(
+            I::<8>::MIN.unsigned_abs(),
+            U::from_u64(i64::MIN.unsigned_abs())
+        );
+    }
+
+    #[test]
+    fn test_consts() {
+        assert_eq!(I::<1>::MIN, I::from_le_bytes(i8::MIN.to_le_bytes()));
+        assert_eq!(I::<1>::MAX, I::from_le_bytes(i8::MAX.to_le_bytes()));
+        assert_eq!(I::<8>::MIN, I::from_le_bytes(i64::MIN.to_le_bytes()));
+        assert_eq!(I::<8>::MAX, I::from_le_bytes(i64::MAX.to_le_bytes()));
+        assert_eq!(I::<4>::MIN, I::min_value());
+        assert_eq!(I::<2>::BITS, 16);
+    }
 
     #[test]
     fn test_one() {
@@ -361,4 +876,170 @@ mod tests {
         assert_eq!(one + zero, one);
         assert_eq!(zero + zero, zero);
     }
+
+    #[test]
+    fn test_try_from_le_slice() {
+        assert_eq!(
+            I::<4>::try_from_le_slice(&[]).unwrap(),
+            I::from_le_bytes(0i32.to_le_bytes())
+        );
+        assert_eq!(
+            I::<4>::try_from_le_slice(&[0x34, 0x12]).unwrap(),
+            I::from_le_bytes(0x1234i32.to_le_bytes())
+        );
+        assert_eq!(
+            I::<4>::try_from_le_slice(&[0xFE]).unwrap(),
+            I::from_le_bytes((-2i32).to_le_bytes())
+        );
+        assert_eq!(
+            I::<4>::try_from_le_slice(&[0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]).unwrap(),
+            I::from_le_bytes((-2i32).to_le_bytes())
+        );
+        assert_eq!(
+            I::<4>::try_from_le_slice(&[1, 2, 3, 4, 0, 0]).unwrap(),
+            I::from_le_bytes(0x0403_0201i32.to_le_bytes())
+        );
+        assert!(I::<4>::try_from_le_slice(&[1, 2, 3, 4, 0, 1]).is_err());
+        assert!(I::<4>::try_from_le_slice(&[1, 2, 3, 0x84, 0]).is_err());
+        assert!(I::<4>::try_from_le_slice(&[1, 2, 3, 4, 0xFF]).is_err());
+    }
+
+    #[test]
+    fn test_neg() {
+        let one: I<2> = I::one();
+        assert_eq!(-one, I([0xFF, 0xFF]));
+        assert_eq!(-I([0, 1]), I([0, 0xFF]));
+        assert_eq!(-I::<2>::zero(), I::zero());
+        assert_eq!(-I::<2>::min_value(), I::min_value());
+    }
+
+    #[test]
+    fn test_wrapping_neg() {
+        for val in [0i8, 1, -1, 2, 127, -127, -128] {
+            assert_eq!(
+                I::<1>::from_le_bytes(val.to_le_bytes()).wrapping_neg(),
+                I::from_le_bytes(val.wrapping_neg().to_le_bytes())
+            );
+        }
+        assert_eq!(I::<1>::min_value().wrapping_neg(), I::<1>::min_value());
+    }
+
+    #[test]
+    fn test_mul() {
+        let three = I::<2>::from_le_bytes(3i16.to_le_bytes());
+        let neg_five = I::<2>::from_le_bytes((-5i16).to_le_bytes());
+        assert_eq!(three * neg_five, I::from_le_bytes((-15i16).to_le_bytes()));
+        assert_eq!(neg_five * neg_five, I::from_le_bytes(25i16.to_le_bytes()));
+    }
+
+    #[test]
+    fn test_div_rem() {
+        let seven = I::<2>::from_le_bytes(7i16.to_le_bytes());
+        let neg_two = I::<2>::from_le_bytes((-2i16).to_le_bytes());
+        assert_eq!(seven / neg_two, I::from_le_bytes((-3i16).to_le_bytes()));
+        assert_eq!(seven % neg_two, I::one());
+        assert_eq!(-seven / neg_two, I::from_le_bytes(3i16.to_le_bytes()));
+        assert_eq!(-seven % neg_two, -I::one());
+    }
+
+    #[test]
+    fn test_gcd() {
+        let neg_48 = I::<2>::from_le_bytes((-48i16).to_le_bytes());
+        let eighteen = I::<2>::from_le_bytes(18i16.to_le_bytes());
+        let six = I::from_le_bytes(6i16.to_le_bytes());
+        assert_eq!(neg_48.gcd(eighteen), six);
+        assert_eq!(eighteen.gcd(neg_48), six);
+        assert_eq!(neg_48.gcd(I::zero()), -neg_48);
+        assert_eq!(I::<2>::zero().gcd(I::zero()), I::zero());
+        assert_eq!(I::<2>::MIN.gcd(I::zero()), I::MIN);
+        assert_eq!(I::<2>::MIN.gcd(I::MIN), I::MIN);
+    }
+
+    #[test]
+    fn test_lcm() {
+        let neg_four = I::<2>::from_le_bytes((-4i16).to_le_bytes());
+        let six = I::<2>::from_le_bytes(6i16.to_le_bytes());
+        assert_eq!(neg_four.lcm(six), I::from_le_bytes(12i16.to_le_bytes()));
+        assert_eq!(neg_four.lcm(I::zero()), I::zero());
+    }
+
+    #[test]
+    fn test_fmt() {
+        let pos = I::from_le_bytes(42i16.to_le_bytes());
+        let neg = I::from_le_bytes((-42i16).to_le_bytes());
+        assert_eq!(format!("{pos}"), "42");
+        assert_eq!(format!("{neg:?}"), "-42");
+        assert_eq!(format!("{pos:+}"), "+42");
+        assert_eq!(format!("{neg:+}"), "-42");
+        assert_eq!(format!("{neg:>5}"), "  -42");
+        assert_eq!(format!("{neg:05}"), "-0042");
+
+        assert_eq!(format!("{pos:#06x}"), "0x002a");
+        assert_eq!(format!("{neg:x}"), format!("{:x}", -42i16));
+        assert_eq!(format!("{neg:#X}"), format!("{:#X}", -42i16));
+        assert_eq!(format!("{neg:o}"), format!("{:o}", -42i16));
+        assert_eq!(format!("{neg:b}"), format!("{:b}", -42i16));
+
+        let min = I::from_le_bytes(i128::MIN.to_le_bytes());
+        assert_eq!(format!("{min}"), format!("{}", i128::MIN));
+    }
+
+    #[test]
+    fn test_from_str() {
+        for val in i8::MIN..=i8::MAX {
+            let i = I::<1>::from_le_bytes(val.to_le_bytes());
+            assert_eq!(format!("{i}").parse::<I<1>>(), Ok(i));
+            assert_eq!(format!("{i:+}").parse::<I<1>>(), Ok(i));
+        }
+
+        let min = I::<16>::from_le_bytes(i128::MIN.to_le_bytes());
+        assert_eq!(format!("{}", i128::MIN).parse::<I<16>>(), Ok(min));
+        assert_eq!("-0".parse::<I<1>>(), Ok(I::zero()));
+        assert_eq!("007".parse::<I<1>>(), Ok(I::from_le_bytes([7])));
+
+        assert_eq!("128".parse::<I<1>>(), Err(ParseIntError::Overflow));
+        assert_eq!("-129".parse::<I<1>>(), Err(ParseIntError::Overflow));
+        assert_eq!("1000".parse::<I<1>>(), Err(ParseIntError::Overflow));
+        assert_eq!("".parse::<I<1>>(), Err(ParseIntError::Empty));
+        assert_eq!("-".parse::<I<1>>(), Err(ParseIntError::Empty));
+        assert_eq!(
+            "1a".parse::<I<1>>(),
+            Err(ParseIntError::InvalidChar('a', 1))
+        );
+        assert_eq!(
+            "+-1".parse::<I<1>>(),
+            Err(ParseIntError::InvalidChar('-', 1))
+        );
+        assert_eq!(
+            "12x4".parse::<I<2>>(),
+            Err(ParseIntError::InvalidChar('x', 2))
+        );
+        assert_eq!(
+            "-12x4".parse::<I<2>>(),
+            Err(ParseIntError::InvalidChar('x', 3))
+        );
+        assert_eq!(
+            "1ä".parse::<I<2>>(),
+            Err(ParseIntError::InvalidChar('ä', 1))
+        );
+    }
+
+    #[test]
+    fn test_widening_mul() {
+        let i8 = |val: i8| I::<1>::from_le_bytes(val.to_le_bytes());
+        let vals = [i8::MIN, i8::MIN + 1, -100, -3, -1, 0, 1, 2, 77, i8::MAX];
+        for &l in &vals {
+            for &r in &vals {
+                for &a in &vals {
+                    let wide = i16::from(l) * i16::from(r) + i16::from(a);
+                    let [low, high] = wide.to_le_bytes();
+                    assert_eq!(
+                        i8(l).widening_mul(i8(r), i8(a)),
+                        (I::from_le_bytes([low]), I::from_le_bytes([high])),
+                        "{l} * {r} + {a}",
+                    );
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,33 @@
+use super::I;
+use crate::u::serde_impl::{deserialize_bytes, serialize_bytes};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serialized as its little-endian two's complement bytes, a fixed-length tuple of `N` `u8`s
+impl<const N: usize> Serialize for I<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(&self.0, serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for I<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_bytes(deserializer).map(I)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let val = I::<8>::from_le_bytes((-2i64).to_le_bytes());
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(json, "[254,255,255,255,255,255,255,255]");
+        assert_eq!(serde_json::from_str::<I<8>>(&json).unwrap(), val);
+
+        let val = I::<8>::from_le_bytes(i64::MAX.to_le_bytes());
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(serde_json::from_str::<I<8>>(&json).unwrap(), val);
+    }
+}
@@ -2,19 +2,27 @@
 //!
 //! ### Note
 //! Unbounded, or 'big' integers, are only available on crate feature `alloc`.
+//!
+//! Implementations of unstable traits, such as [`core::iter::Step`], are available on crate
+//! feature `nightly`.
 
 #![no_std]
-#[cfg(feature = "std")]
+#![cfg_attr(feature = "nightly", feature(step_trait))]
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 mod big_int;
 mod i;
 mod u;
 // #[cfg(test)]
 // mod tests;
 
-#[cfg(feature = "std")]
-pub use big_int::BigInt;
-pub use i::I;
-pub use u::U;
+#[cfg(all(feature = "alloc", feature = "rand"))]
+pub use big_int::UniformBigInt;
+#[cfg(feature = "alloc")]
+pub use big_int::{BigInt, BigIntIn, BigIntStore, FromStrError, Sign};
+pub use i::{ParseIntError, I};
+#[cfg(feature = "rand")]
+pub use u::UniformU;
+pub use u::{Radix, TryFromSliceError, U};
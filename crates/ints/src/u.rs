@@ -2,28 +2,81 @@
 
 #![allow(unused_variables)]
 
-use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
 use core::cmp::Ordering;
 use core::iter::Product;
-use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Rem, Shl, Shr, Sub};
+use core::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Mul, MulAssign, Not, Rem, RemAssign, Shl, Shr, Sub, SubAssign,
+};
 use core::{array, fmt, iter};
-use numeric_bits::algos::{BitwiseDiv, ElementCmp};
 use numeric_bits::algos::{ElementAdd, ElementMul, ElementShl, ElementShr, ElementSub};
+use numeric_bits::algos::{ElementCmp, ElementDiv};
 use numeric_bits::utils::const_reverse;
 use numeric_static_iter::{IntoStaticIter, StaticIter};
+use numeric_traits::bytes::ConvertBytes;
 use numeric_traits::cast::{FromChecked, FromSaturating, FromTruncating, IntoChecked};
-use numeric_traits::class::{Bounded, Integral, Numeric, Unsigned};
+use numeric_traits::class::{Bounded, BoundedBit, Integral, Numeric, Unsigned};
 use numeric_traits::identity::{One, Zero};
 use numeric_traits::ops::checked::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+use numeric_traits::ops::overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub};
 use numeric_traits::ops::saturating::{SaturatingAdd, SaturatingMul, SaturatingSub};
-use numeric_traits::ops::wrapping::{WrappingAdd, WrappingSub};
-use numeric_traits::ops::Pow;
+use numeric_traits::ops::widening::WideningMul;
+use numeric_traits::ops::wrapping::{WrappingAdd, WrappingNeg, WrappingSub};
+use numeric_traits::ops::{Gcd, Lcm, Pow};
 use numeric_utils::{static_assert, static_assert_traits};
 
 #[cfg(feature = "rand")]
 mod rand_impl;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_impl;
+
+#[cfg(feature = "rand")]
+pub use rand_impl::UniformU;
+
+/// The error for when you try to create a bounded integer from a byte slice holding a value too
+/// large to fit in the type being created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TryFromSliceError(());
+
+impl TryFromSliceError {
+    pub(crate) fn new() -> Self {
+        Self(())
+    }
+}
+
+/// Display adapter for writing a [`U`] in an arbitrary base from 2 to 36, using lowercase digits.
+/// Created by [`U::radix`].
+#[derive(Debug, Clone, Copy)]
+pub struct Radix<'a, const N: usize> {
+    val: &'a U<N>,
+    radix: u32,
+}
+
+pub(crate) const LOWER_DIGITS: &[char] = &[
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i',
+    'j', 'k', 'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z',
+];
+pub(crate) const UPPER_DIGITS: &[char] = &[
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F',
+];
+
+impl<const N: usize> fmt::Display for Radix<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.val
+            .fmt_base(f, true, self.radix as usize, "", LOWER_DIGITS)
+    }
+}
 
 /// N-byte bounded, unsigned integer. `U<1> == u8`, `U<16> == u128`, etc.
+///
+/// `N` must be at least 1 - a zero-width integer fails to compile:
+///
+/// ```compile_fail
+/// # use numeric_ints::U;
+/// let _ = U::<0>::new();
+/// ```
 pub struct U<const N: usize>([u8; N]);
 
 static_assert!(size_of::<U<2>>() == 2);
@@ -32,22 +85,40 @@ static_assert!(size_of::<U<8>>() == 8);
 static_assert_traits!(U<4>: Send + Sync);
 
 impl<const N: usize> U<N> {
+    /// Evaluated by every constructor, so that naming a zero-width integer is a compile error
+    /// rather than a panic the first time its bytes are indexed. Anything creating a value from
+    /// new bytes, such as random sampling, must go through a constructor. Only operations on an
+    /// existing value, which has already passed this check, may build `U` directly.
+    const NONZERO_WIDTH: () = assert!(N > 0, "U<0> is not a valid integer width");
+
+    /// The smallest value that can be represented by this integer type, zero
+    pub const MIN: U<N> = U::new();
+
+    /// The largest value that can be represented by this integer type, `2^BITS - 1`
+    pub const MAX: U<N> = U::from_le_bytes([u8::MAX; N]);
+
+    /// The size of this integer type in bits
+    pub const BITS: u32 = (N * 8) as u32;
+
     /// Create a new instance containing the default value (0)
     #[inline]
     #[must_use]
     pub const fn new() -> U<N> {
+        let () = Self::NONZERO_WIDTH;
         U([0; N])
     }
 
     /// Create a value from raw bytes, laid out in little-endian order
     #[must_use]
     pub const fn from_le_bytes(bytes: [u8; N]) -> U<N> {
+        let () = Self::NONZERO_WIDTH;
         U(bytes)
     }
 
     /// Create a value from raw bytes, laid out in big-endian order
     #[must_use]
     pub const fn from_be_bytes(bytes: [u8; N]) -> U<N> {
+        let () = Self::NONZERO_WIDTH;
         U(const_reverse(bytes))
     }
 
@@ -61,6 +132,25 @@ impl<const N: usize> U<N> {
         }
     }
 
+    /// Create a value from a slice of bytes of any length, laid out in little-endian order.
+    /// Shorter slices are zero-extended.
+    ///
+    /// # Errors
+    ///
+    /// If the slice is longer than `N` bytes and any of the extra high bytes are nonzero
+    pub fn try_from_le_slice(bytes: &[u8]) -> Result<U<N>, TryFromSliceError> {
+        let () = Self::NONZERO_WIDTH;
+        let mut out = [0; N];
+        let len = bytes.len().min(N);
+        out[..len].copy_from_slice(&bytes[..len]);
+
+        if bytes[len..].iter().any(|&b| b != 0) {
+            Err(TryFromSliceError::new())
+        } else {
+            Ok(U(out))
+        }
+    }
+
     /// Convert this value to raw bytes, laid out in little-endian order
     #[must_use]
     pub const fn to_le_bytes(self) -> [u8; N] {
@@ -83,28 +173,156 @@ impl<const N: usize> U<N> {
         }
     }
 
-    fn write_base<W: fmt::Write>(&self, base: usize, w: &mut W, chars: &[char]) -> fmt::Result {
+    /// Raise this value to the power of `exp`, returning the result along with whether the
+    /// multiplication overflowed. On overflow, the wrapped value is returned.
+    #[must_use]
+    pub fn overflowing_pow(self, mut exp: u32) -> (U<N>, bool) {
+        // Square-and-multiply - only square the base while there are bits left to consume, so an
+        // unused square can't report a spurious overflow
+        let mut base = self;
+        let mut out = U::one();
+        let mut overflow = false;
+
+        while exp > 0 {
+            if exp & 1 == 1 {
+                overflow |= ElementMul::mul_overflowing(&mut out.0, &base.0).1;
+            }
+            exp >>= 1;
+            if exp > 0 {
+                let square = base;
+                overflow |= ElementMul::mul_overflowing(&mut base.0, &square.0).1;
+            }
+        }
+
+        (out, overflow)
+    }
+
+    /// Raise this value to the power of `exp`, wrapping around at the boundary of the type.
+    #[must_use]
+    pub fn wrapping_pow(self, exp: u32) -> U<N> {
+        self.overflowing_pow(exp).0
+    }
+
+    /// Calculate `self + rhs + carry`, returning the sum and the carry out of the top. Chaining
+    /// the carry through several additions allows building wider integers out of this one.
+    #[must_use]
+    pub fn carrying_add(mut self, rhs: U<N>, carry: bool) -> (U<N>, bool) {
+        let (_, overflow1) = ElementAdd::add_overflowing(&mut self.0, &rhs.0);
+        let (_, overflow2) = ElementAdd::add_overflowing(&mut self.0, &[u8::from(carry)]);
+        (self, overflow1 || overflow2)
+    }
+
+    /// Calculate `self - rhs - borrow`, returning the difference and whether a borrow was needed
+    /// out of the top. Chaining the borrow through several subtractions allows building wider
+    /// integers out of this one.
+    #[must_use]
+    pub fn borrowing_sub(mut self, rhs: U<N>, borrow: bool) -> (U<N>, bool) {
+        let (_, overflow1) = ElementSub::sub_overflowing(&mut self.0, &rhs.0);
+        let (_, overflow2) = ElementSub::sub_overflowing(&mut self.0, &[u8::from(borrow)]);
+        (self, overflow1 || overflow2)
+    }
+
+    /// Calculate `self * b + c`. Like the operators, this panics on overflow in debug builds and
+    /// wraps in release builds. As the value is unsigned, the result overflows exactly when
+    /// either step would, so this is the same as `self * b + c`.
+    #[must_use]
+    pub fn mul_add(self, b: U<N>, c: U<N>) -> U<N> {
+        self * b + c
+    }
+
+    /// Get the number of significant bits in this value, the position of the highest set bit plus
+    /// one. Zero has no significant bits.
+    #[must_use]
+    pub fn magnitude_bits(&self) -> u32 {
+        match self.0.iter().rposition(|&byte| byte != 0) {
+            Some(idx) => (idx * 8) as u32 + (u8::BITS - self.0[idx].leading_zeros()),
+            None => 0,
+        }
+    }
+
+    /// Get an adapter which displays this value in the given base, using lowercase digits for
+    /// values past 9.
+    ///
+    /// # Panics
+    ///
+    /// If `radix` is not in the range `2..=36`
+    #[must_use]
+    pub fn radix(&self, radix: u32) -> Radix<'_, N> {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in the range 2..=36, got {radix}"
+        );
+        Radix { val: self, radix }
+    }
+
+    /// Convert this value to a string in the given base, using lowercase digits for values past 9.
+    ///
+    /// # Panics
+    ///
+    /// If `radix` is not in the range `2..=36`
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        self.radix(radix).to_string()
+    }
+
+    /// Write this value to a formatter in the given base, respecting its width, fill, sign and
+    /// alternate flags. The sign is passed separately, so signed types can format their magnitude.
+    pub(crate) fn fmt_base(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        is_nonnegative: bool,
+        base: usize,
+        prefix: &str,
+        chars: &[char],
+    ) -> fmt::Result {
+        // Binary is the longest output, at eight digits per byte. Digits are found least
+        // significant first, so fill the buffer from the back.
+        let mut buf = [[0u8; 8]; N];
+        let buf = buf.as_flattened_mut();
+        let mut start = buf.len();
+
         // This is the simplest way - mod base for digit, div base for next digit
         // It isn't super fast though, so there are probably optimization improvements
         let base: U<N> = base.into_checked().unwrap();
-        let mut digits = Vec::new();
-        let mut scratch = self.clone();
-
-        while scratch > U::zero() {
-            let digit = u8::from_checked(scratch.clone() % base)
-                .expect("Mod base should always be less than 255");
-            digits.push(digit);
-            scratch = scratch / base;
+        let mut scratch = *self;
+        loop {
+            let digit =
+                u8::from_checked(scratch % base).expect("Mod base should always be less than 255");
+            start -= 1;
+            buf[start] = chars[usize::from(digit)] as u8;
+            scratch /= base;
+            if scratch == U::zero() {
+                break;
+            }
         }
 
-        if digits.is_empty() {
-            digits.push(0);
+        let digits = core::str::from_utf8(&buf[start..]).expect("Digits should be ASCII");
+        f.pad_integral(is_nonnegative, prefix, digits)
+    }
+
+    fn trailing_zero_bits(&self) -> usize {
+        let mut out = 0;
+        for &b in &self.0 {
+            if b == 0 {
+                out += 8;
+            } else {
+                return out + b.trailing_zeros() as usize;
+            }
         }
+        out
+    }
 
-        for &d in digits.iter().rev() {
-            w.write_char(chars[d as usize])?;
+    fn leading_zero_bits(&self) -> usize {
+        let mut out = 0;
+        for &b in self.0.iter().rev() {
+            if b == 0 {
+                out += 8;
+            } else {
+                return out + b.leading_zeros() as usize;
+            }
         }
-        Ok(())
+        out
     }
 }
 
@@ -231,8 +449,31 @@ impl<const N: usize> fmt::Debug for U<N> {
 
 impl<const N: usize> fmt::Display for U<N> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        const DIGITS: &[char] = &['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
-        self.write_base(10, f, DIGITS)
+        self.fmt_base(f, true, 10, "", LOWER_DIGITS)
+    }
+}
+
+impl<const N: usize> fmt::Binary for U<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_base(f, true, 2, "0b", LOWER_DIGITS)
+    }
+}
+
+impl<const N: usize> fmt::Octal for U<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_base(f, true, 8, "0o", LOWER_DIGITS)
+    }
+}
+
+impl<const N: usize> fmt::LowerHex for U<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_base(f, true, 16, "0x", LOWER_DIGITS)
+    }
+}
+
+impl<const N: usize> fmt::UpperHex for U<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_base(f, true, 16, "0x", UPPER_DIGITS)
     }
 }
 
@@ -241,7 +482,7 @@ impl<const N: usize> Add for U<N> {
 
     fn add(self, rhs: Self) -> Self::Output {
         if cfg!(debug_assertions) {
-            self.checked_add(rhs).unwrap()
+            self.checked_add(rhs).expect("attempt to add with overflow")
         } else {
             self.wrapping_add(rhs)
         }
@@ -253,7 +494,8 @@ impl<const N: usize> Sub for U<N> {
 
     fn sub(self, rhs: Self) -> Self::Output {
         if cfg!(debug_assertions) {
-            self.checked_sub(rhs).unwrap()
+            self.checked_sub(rhs)
+                .expect("attempt to subtract with overflow")
         } else {
             self.wrapping_sub(rhs)
         }
@@ -265,7 +507,7 @@ impl<const N: usize> Mul for U<N> {
 
     fn mul(mut self, rhs: Self) -> Self::Output {
         #[cfg(debug_assertions)]
-        ElementMul::mul_checked(&mut self.0, &rhs.0).unwrap();
+        ElementMul::mul_checked(&mut self.0, &rhs.0).expect("attempt to multiply with overflow");
         #[cfg(not(debug_assertions))]
         ElementMul::mul_wrapping(&mut self.0, &rhs.0);
         self
@@ -276,10 +518,9 @@ impl<const N: usize> Div for U<N> {
     type Output = Self;
 
     fn div(mut self, rhs: Self) -> Self::Output {
-        #[cfg(debug_assertions)]
-        BitwiseDiv::div_long_checked(&mut self.0, &rhs.0, &mut [0; N]).unwrap();
-        #[cfg(not(debug_assertions))]
-        BitwiseDiv::div_long_wrapping(&mut self.0, &rhs.0, &mut [0; N]);
+        // Unsigned division can't overflow, so the only failure is a zero divisor
+        assert!(!rhs.is_zero(), "attempt to divide by zero");
+        ElementDiv::div_long_wrapping(&mut self.0, &rhs.0);
         self
     }
 }
@@ -288,14 +529,38 @@ impl<const N: usize> Rem for U<N> {
     type Output = Self;
 
     fn rem(mut self, rhs: Self) -> Self::Output {
-        #[cfg(debug_assertions)]
-        BitwiseDiv::rem_long_checked(&mut self.0, &rhs.0, &mut [0; N]).unwrap();
-        #[cfg(not(debug_assertions))]
-        BitwiseDiv::rem_long_checked(&mut self.0, &rhs.0, &mut [0; N]).unwrap();
+        assert!(
+            !rhs.is_zero(),
+            "attempt to calculate the remainder with a divisor of zero"
+        );
+        ElementDiv::rem_long_wrapping(&mut self.0, &rhs.0);
         self
     }
 }
 
+macro_rules! impl_assign_op {
+    ($($trait:ident, $meth:ident, $op:tt);* $(;)?) => {
+        $(
+        impl<const N: usize> $trait for U<N> {
+            fn $meth(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+        )*
+    };
+}
+
+impl_assign_op!(
+    AddAssign, add_assign, +;
+    SubAssign, sub_assign, -;
+    MulAssign, mul_assign, *;
+    DivAssign, div_assign, /;
+    RemAssign, rem_assign, %;
+    BitAndAssign, bitand_assign, &;
+    BitOrAssign, bitor_assign, |;
+    BitXorAssign, bitxor_assign, ^;
+);
+
 impl<const N: usize> Not for U<N> {
     type Output = Self;
 
@@ -346,39 +611,47 @@ impl<const N: usize> BitXor for U<N> {
     }
 }
 
+impl<const N: usize> U<N> {
+    /// Convert a shift amount of this type into a `usize`. Amounts too large for a `usize` are
+    /// always out of range, so in debug builds they become `usize::MAX` to trigger the overflow
+    /// panic, while in release builds they're reduced modulo `BITS` so the shift wraps correctly.
+    pub(crate) fn shift_amount(self) -> usize {
+        usize::from_checked(self).unwrap_or_else(|| {
+            if cfg!(debug_assertions) {
+                usize::MAX
+            } else {
+                let bits = U::from_checked(N * 8).expect("BITS fits in U<N>");
+                usize::from_checked(self % bits).expect("Shift amount is less than BITS")
+            }
+        })
+    }
+}
+
 impl<const N: usize> Shl for U<N> {
     type Output = Self;
 
-    fn shl(mut self, rhs: Self) -> Self::Output {
-        let val: usize = usize::from_checked(rhs).unwrap();
-        #[cfg(debug_assertions)]
-        ElementShl::shl_checked(&mut self.0, val).unwrap();
-        #[cfg(not(debug_assertions))]
-        ElementShl::shl_wrapping(&mut self.0, val);
-        self
+    fn shl(self, rhs: Self) -> Self::Output {
+        self << rhs.shift_amount()
     }
 }
 
 impl<const N: usize> Shr for U<N> {
     type Output = Self;
 
-    fn shr(mut self, rhs: Self) -> Self::Output {
-        let val: usize = usize::from_checked(rhs).unwrap();
-        #[cfg(debug_assertions)]
-        ElementShr::shr_checked(&mut self.0, val).unwrap();
-        #[cfg(not(debug_assertions))]
-        ElementShr::shr_wrapping(&mut self.0, val);
-        self
+    fn shr(self, rhs: Self) -> Self::Output {
+        self >> rhs.shift_amount()
     }
 }
 
+// Like the primitive integers, shifting by `BITS` or more panics in debug builds, and uses the
+// shift amount modulo `BITS` in release builds.
+
 impl<const N: usize> Shl<usize> for U<N> {
     type Output = Self;
 
     fn shl(mut self, rhs: usize) -> Self::Output {
         #[cfg(debug_assertions)]
-        ElementShl::shl_checked(&mut self.0, rhs).unwrap();
-        #[cfg(not(debug_assertions))]
+        assert!(rhs < N * 8, "attempt to shift left with overflow");
         ElementShl::shl_wrapping(&mut self.0, rhs);
         self
     }
@@ -389,20 +662,85 @@ impl<const N: usize> Shr<usize> for U<N> {
 
     fn shr(mut self, rhs: usize) -> Self::Output {
         #[cfg(debug_assertions)]
-        ElementShr::shr_checked(&mut self.0, rhs).unwrap();
-        #[cfg(not(debug_assertions))]
+        assert!(rhs < N * 8, "attempt to shift right with overflow");
         ElementShr::shr_wrapping(&mut self.0, rhs);
         self
     }
 }
 
+macro_rules! impl_shift_prim {
+    ($($ty:ty),* $(,)?) => {
+        $(
+        impl<const N: usize> Shl<$ty> for U<N> {
+            type Output = Self;
+
+            fn shl(self, rhs: $ty) -> Self::Output {
+                self << usize::try_from(rhs).expect("attempt to shift by a negative amount")
+            }
+        }
+
+        impl<const N: usize> Shr<$ty> for U<N> {
+            type Output = Self;
+
+            fn shr(self, rhs: $ty) -> Self::Output {
+                self >> usize::try_from(rhs).expect("attempt to shift by a negative amount")
+            }
+        }
+        )*
+    };
+}
+
+impl_shift_prim!(u32, i32);
+
+impl<const N: usize> ConvertBytes<N> for U<N> {
+    #[inline]
+    fn from_ne_bytes(bytes: [u8; N]) -> Self {
+        U::from_ne_bytes(bytes)
+    }
+
+    #[inline]
+    fn from_be_bytes(bytes: [u8; N]) -> Self {
+        U::from_be_bytes(bytes)
+    }
+
+    #[inline]
+    fn from_le_bytes(bytes: [u8; N]) -> Self {
+        U::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    fn to_ne_bytes(self) -> [u8; N] {
+        U::to_ne_bytes(self)
+    }
+
+    #[inline]
+    fn to_be_bytes(self) -> [u8; N] {
+        U::to_be_bytes(self)
+    }
+
+    #[inline]
+    fn to_le_bytes(self) -> [u8; N] {
+        U::to_le_bytes(self)
+    }
+}
+
 impl<const N: usize> Bounded for U<N> {
     fn min_value() -> Self {
-        U([0; N])
+        U::MIN
     }
 
     fn max_value() -> Self {
-        U([u8::MAX; N])
+        U::MAX
+    }
+}
+
+impl<const N: usize> BoundedBit for U<N> {
+    fn leading_zeros(self) -> Self {
+        U::from_checked(self.leading_zero_bits()).expect("Bit count of a U<N> always fits in U<N>")
+    }
+
+    fn trailing_zeros(self) -> Self {
+        U::from_checked(self.trailing_zero_bits()).expect("Bit count of a U<N> always fits in U<N>")
     }
 }
 
@@ -442,6 +780,75 @@ impl<const N: usize> Pow for U<N> {
     }
 }
 
+impl<const N: usize> Gcd for U<N> {
+    type Output = Self;
+
+    fn gcd(self, rhs: Self) -> Self::Output {
+        // Binary GCD - factor out shared powers of two, then repeatedly subtract the smaller value
+        // from the larger one, removing any new factors of two as we go.
+        if self.is_zero() {
+            return rhs;
+        } else if rhs.is_zero() {
+            return self;
+        }
+
+        let shift = usize::min(self.trailing_zero_bits(), rhs.trailing_zero_bits());
+        let mut u = self >> self.trailing_zero_bits();
+        let mut v = rhs;
+
+        loop {
+            v = v >> v.trailing_zero_bits();
+            match v.checked_sub(u) {
+                Some(diff) => v = diff,
+                None => (u, v) = (v, u - v),
+            }
+
+            if v.is_zero() {
+                return u << shift;
+            }
+        }
+    }
+}
+
+impl<const N: usize> Lcm for U<N> {
+    type Output = Self;
+
+    fn lcm(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return U::zero();
+        }
+
+        (self / self.gcd(rhs)) * rhs
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<const N: usize> iter::Step for U<N> {
+    fn steps_between(start: &Self, end: &Self) -> (usize, Option<usize>) {
+        let Some(diff) = end.checked_sub(*start) else {
+            return (0, None);
+        };
+
+        const SIZE: usize = size_of::<usize>();
+        if diff.0.iter().skip(SIZE).any(|&b| b != 0) {
+            return (usize::MAX, None);
+        }
+        let mut bytes = [0; SIZE];
+        let len = N.min(SIZE);
+        bytes[..len].copy_from_slice(&diff.0[..len]);
+        let steps = usize::from_le_bytes(bytes);
+        (steps, Some(steps))
+    }
+
+    fn forward_checked(start: Self, count: usize) -> Option<Self> {
+        start.checked_add(U::try_from_le_slice(&count.to_le_bytes()).ok()?)
+    }
+
+    fn backward_checked(start: Self, count: usize) -> Option<Self> {
+        start.checked_sub(U::try_from_le_slice(&count.to_le_bytes()).ok()?)
+    }
+}
+
 impl<const N: usize> Product<U<N>> for U<N> {
     fn product<I: Iterator<Item = U<N>>>(iter: I) -> Self {
         iter.fold(U::one(), |a, b| a * b)
@@ -479,11 +886,52 @@ impl<const N: usize> CheckedDiv for U<N> {
     type Output = Self;
 
     fn checked_div(mut self, rhs: Self) -> Option<Self> {
-        BitwiseDiv::div_long_checked(&mut self.0, &rhs.0, &mut [0; N])?;
+        ElementDiv::div_long_checked(&mut self.0, &rhs.0)?;
         Some(self)
     }
 }
 
+impl<const N: usize> OverflowingAdd for U<N> {
+    type Output = Self;
+
+    fn overflowing_add(mut self, rhs: Self) -> (Self, bool) {
+        let (_, overflow) = ElementAdd::add_overflowing(&mut self.0, &rhs.0);
+        (self, overflow)
+    }
+}
+
+impl<const N: usize> OverflowingSub for U<N> {
+    type Output = Self;
+
+    fn overflowing_sub(mut self, rhs: Self) -> (Self, bool) {
+        let (_, overflow) = ElementSub::sub_overflowing(&mut self.0, &rhs.0);
+        (self, overflow)
+    }
+}
+
+impl<const N: usize> OverflowingMul for U<N> {
+    type Output = Self;
+
+    fn overflowing_mul(mut self, rhs: Self) -> (Self, bool) {
+        let (_, overflow) = ElementMul::mul_overflowing(&mut self.0, &rhs.0);
+        (self, overflow)
+    }
+}
+
+impl<const N: usize> WideningMul for U<N> {
+    fn widening_mul(self, mul: Self, add: Self) -> (Self, Self) {
+        // The result always fits in `2N` bytes, so zero-extend everything to that width, as a
+        // low and high half on the stack
+        let extend = |val: U<N>| [val.0, [0; N]];
+
+        let mut wide = extend(self);
+        ElementMul::mul_wrapping(wide.as_flattened_mut(), extend(mul).as_flattened());
+        ElementAdd::add_wrapping(wide.as_flattened_mut(), extend(add).as_flattened());
+        let [low, high] = wide;
+        (U(low), U(high))
+    }
+}
+
 impl<const N: usize> WrappingAdd for U<N> {
     type Output = Self;
 
@@ -502,6 +950,16 @@ impl<const N: usize> WrappingSub for U<N> {
     }
 }
 
+impl<const N: usize> WrappingNeg for U<N> {
+    type Output = Self;
+
+    fn wrapping_neg(self) -> Self::Output {
+        let mut out = !self;
+        ElementAdd::add_wrapping(&mut out.0, &[1]);
+        out
+    }
+}
+
 impl<const N: usize> SaturatingAdd for U<N> {
     type Output = Self;
 
@@ -537,7 +995,7 @@ impl<const N: usize> SaturatingMul for U<N> {
 
 impl<const N: usize> Zero for U<N> {
     fn zero() -> Self {
-        U([0; N])
+        U::new()
     }
 
     fn is_zero(&self) -> bool {
@@ -547,7 +1005,7 @@ impl<const N: usize> Zero for U<N> {
 
 impl<const N: usize> One for U<N> {
     fn one() -> Self {
-        U(array::from_fn(|idx| if idx == 0 { 1 } else { 0 }))
+        U::from_le_bytes(array::from_fn(|idx| if idx == 0 { 1 } else { 0 }))
     }
 
     fn is_one(&self) -> bool {
@@ -574,7 +1032,7 @@ macro_rules! impl_unsign_cast {
                     Some(<$num>::from_le_bytes(arr))
                 } else {
                     for i in 0..N {
-                        if i <= SIZE {
+                        if i < SIZE {
                             arr[i] = val.0[i];
                         } else {
                             if val.0[i] != 0 {
@@ -589,6 +1047,7 @@ macro_rules! impl_unsign_cast {
 
         impl<const N: usize> FromSaturating<U<N>> for $num {
             fn saturate_from(val: U<N>) -> Self {
+                // Unsigned values can only be out of range by being too large
                 <$num>::from_checked(val).unwrap_or(<$num>::MAX)
             }
         }
@@ -610,13 +1069,13 @@ macro_rules! impl_unsign_cast {
                 let bytes = val.to_le_bytes();
                 let mut arr = [0; N];
                 if N >= SIZE {
-                    for i in 0..N {
+                    for i in 0..SIZE {
                         arr[i] = bytes[i];
                     }
                     Some(U::from_le_bytes(arr))
                 } else {
-                    for i in 0..N {
-                        if i <= N {
+                    for i in 0..SIZE {
+                        if i < N {
                             arr[i] = bytes[i];
                         } else {
                             if bytes[i] != 0 {
@@ -631,6 +1090,41 @@ macro_rules! impl_unsign_cast {
     };
 }
 
+macro_rules! impl_unsign_cmp {
+    ($num:ty) => {
+        impl<const N: usize> PartialEq<$num> for U<N> {
+            fn eq(&self, other: &$num) -> bool {
+                ElementCmp::cmp(&self.0, &other.to_le_bytes()).is_eq()
+            }
+        }
+
+        impl<const N: usize> PartialOrd<$num> for U<N> {
+            fn partial_cmp(&self, other: &$num) -> Option<Ordering> {
+                Some(ElementCmp::cmp(&self.0, &other.to_le_bytes()))
+            }
+        }
+
+        impl<const N: usize> PartialEq<U<N>> for $num {
+            fn eq(&self, other: &U<N>) -> bool {
+                other == self
+            }
+        }
+
+        impl<const N: usize> PartialOrd<U<N>> for $num {
+            fn partial_cmp(&self, other: &U<N>) -> Option<Ordering> {
+                other.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+
+impl_unsign_cmp!(u8);
+impl_unsign_cmp!(u16);
+impl_unsign_cmp!(u32);
+impl_unsign_cmp!(u64);
+impl_unsign_cmp!(u128);
+impl_unsign_cmp!(usize);
+
 impl_unsign_cast!(u8);
 impl_unsign_cast!(u16);
 impl_unsign_cast!(u32);
@@ -638,9 +1132,53 @@ impl_unsign_cast!(u64);
 impl_unsign_cast!(u128);
 impl_unsign_cast!(usize);
 
+impl<const N: usize, const M: usize> FromChecked<U<M>> for U<N> {
+    fn from_checked(val: U<M>) -> Option<Self> {
+        if val.0[N.min(M)..].iter().any(|&b| b != 0) {
+            return None;
+        }
+        let mut arr = [0; N];
+        arr[..N.min(M)].copy_from_slice(&val.0[..N.min(M)]);
+        Some(U::from_le_bytes(arr))
+    }
+}
+
+impl<const N: usize, const M: usize> FromSaturating<U<M>> for U<N> {
+    fn saturate_from(val: U<M>) -> Self {
+        U::from_checked(val).unwrap_or(U::max_value())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_convert_bytes() {
+        fn round_trip<T: ConvertBytes<N>, const N: usize>(val: T) -> T {
+            T::from_be_bytes(val.to_be_bytes())
+        }
+
+        let val = U::<4>::from_u32(0x1234_5678);
+        assert_eq!(ConvertBytes::to_be_bytes(val), 0x1234_5678u32.to_be_bytes());
+        assert_eq!(ConvertBytes::to_le_bytes(val), 0x1234_5678u32.to_le_bytes());
+        assert_eq!(round_trip(val), val);
+        assert_eq!(
+            <U<4> as ConvertBytes<4>>::from_be_bytes([0x12, 0x34, 0x56, 0x78]),
+            val
+        );
+    }
+
+    #[test]
+    fn test_consts() {
+        assert_eq!(U::<1>::MAX.as_u8(), 255);
+        assert_eq!(U::<4>::MAX.as_u32(), u32::MAX);
+        assert_eq!(U::<2>::MIN, U::new());
+        assert_eq!(U::<16>::MAX, U::max_value());
+        assert_eq!(U::<1>::BITS, 8);
+        assert_eq!(U::<16>::BITS, 128);
+    }
 
     #[test]
     fn test_one() {
@@ -662,12 +1200,223 @@ mod tests {
         assert_eq!(two, two);
     }
 
+    #[test]
+    fn test_mul_add() {
+        let u32 = U::<4>::from_u32;
+        assert_eq!(u32(3).mul_add(u32(4), u32(5)), u32(17));
+        assert_eq!(
+            u32(0x1_0000).mul_add(u32(0xFFFF), u32(0xFFFF)),
+            u32(u32::MAX)
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "attempt to add with overflow"]
+    fn test_mul_add_overflow() {
+        let _ = U::<4>::from_u32(0x1_0000).mul_add(U::from_u32(0xFFFF), U::from_u32(0x1_0000));
+    }
+
+    #[test]
+    fn test_magnitude_bits() {
+        assert_eq!(U::<4>::from_u32(0).magnitude_bits(), 0);
+        assert_eq!(U::<4>::from_u32(1).magnitude_bits(), 1);
+        assert_eq!(U::<4>::from_u32(0xFF).magnitude_bits(), 8);
+        assert_eq!(U::<4>::from_u32(0x100).magnitude_bits(), 9);
+        assert_eq!(U::<4>::MAX.magnitude_bits(), 32);
+        for val in [3u32, 0x1234, 0x8000_0000, 0x00FF_0001] {
+            assert_eq!(
+                U::<4>::from_u32(val).magnitude_bits(),
+                u32::BITS - val.leading_zeros()
+            );
+        }
+    }
+
+    #[test]
+    fn test_ord() {
+        assert!(U::<2>::from_u16(0x0100) > U::<2>::from_u16(0x0001));
+        assert!(U::<2>([0, 1]) > U([0xFF, 0]));
+
+        // Every pair of values made from a set of interesting bytes in either position
+        let bytes = [0x00, 0x01, 0x02, 0x7F, 0x80, 0xFE, 0xFF];
+        let vals = bytes
+            .map(|high| bytes.map(|low| u16::from_le_bytes([low, high])))
+            .concat();
+        for &a in &vals {
+            for &b in &vals {
+                assert_eq!(
+                    U::<2>::from_u16(a).cmp(&U::from_u16(b)),
+                    a.cmp(&b),
+                    "{a:#06x} cmp {b:#06x}"
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_add() {
         let one: U<3> = U::one();
         assert_eq!(one + one, U([2, 0, 0]));
     }
 
+    #[test]
+    fn test_cmp_prim() {
+        let val = U::<4>::from_u32(0x1234_5678);
+        assert_eq!(val, 0x1234_5678u32);
+        assert_eq!(val, 0x1234_5678u64);
+        assert_eq!(0x1234_5678usize, val);
+        assert_ne!(val, 0x5678u16);
+        assert_ne!(val, 0x1_1234_5678u64);
+
+        assert!(val > 0x5678u16);
+        assert!(val < 0x1234_5679u32);
+        assert!(val < 0x1_0000_0000u64);
+        assert!(U::<4>::max_value() < u128::MAX);
+        assert!(u64::MAX > U::<4>::max_value());
+        assert!(U::<16>::max_value() > u64::MAX);
+        assert!(U::<2>::zero() <= 0u8);
+    }
+
+    #[test]
+    fn test_from_checked_narrowing() {
+        assert_eq!(U::<2>::from_checked(u32::MAX), None);
+        assert_eq!(U::<2>::from_checked(0x1_0000u32), None);
+        assert_eq!(U::<2>::from_checked(0xFFFFu32), Some(U::from_u16(0xFFFF)));
+        assert_eq!(U::<1>::from_checked(0x100usize), None);
+        assert_eq!(U::<1>::from_checked(0xFFusize), Some(U::from_u8(0xFF)));
+        assert_eq!(U::<8>::from_checked(u128::MAX), None);
+        assert_eq!(
+            U::<8>::from_checked(u128::from(u64::MAX)),
+            Some(U::from_u64(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn test_saturate() {
+        assert_eq!(u16::saturate_from(U::<8>::max_value()), u16::MAX);
+        assert_eq!(u16::saturate_from(U::<8>::from_u64(1234)), 1234);
+        assert_eq!(u16::saturate_from(U::<8>::from_u64(0x1_0000)), u16::MAX);
+        assert_eq!(
+            u128::saturate_from(U::<8>::max_value()),
+            u128::from(u64::MAX)
+        );
+        assert_eq!(u8::saturate_from(U::<1>::max_value()), u8::MAX);
+
+        assert_eq!(
+            U::<2>::saturate_from(U::<8>::max_value()),
+            U::<2>::max_value()
+        );
+        assert_eq!(
+            U::<2>::saturate_from(U::<8>::from_u64(1234)),
+            U::from_u16(1234)
+        );
+        assert_eq!(
+            U::<8>::saturate_from(U::<2>::max_value()),
+            U::from_u64(u64::from(u16::MAX))
+        );
+        assert_eq!(U::<2>::from_checked(U::<4>::from_u32(0x1_0000)), None);
+        assert_eq!(
+            U::<4>::from_checked(U::<4>::from_u32(0x1_0000)),
+            Some(U::from_u32(0x1_0000))
+        );
+    }
+
+    #[test]
+    fn test_try_from_le_slice() {
+        assert_eq!(U::<4>::try_from_le_slice(&[]), Ok(U::from_u32(0)));
+        assert_eq!(
+            U::<4>::try_from_le_slice(&[0x34, 0x12]),
+            Ok(U::from_u32(0x1234))
+        );
+        assert_eq!(
+            U::<4>::try_from_le_slice(&[1, 2, 3, 4]),
+            Ok(U::from_u32(0x0403_0201))
+        );
+        assert_eq!(
+            U::<4>::try_from_le_slice(&[1, 2, 3, 4, 0, 0]),
+            Ok(U::from_u32(0x0403_0201))
+        );
+        assert!(U::<4>::try_from_le_slice(&[1, 2, 3, 4, 0, 1]).is_err());
+        assert!(U::<2>::try_from_le_slice(&[0, 0, 0xFF]).is_err());
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_step() {
+        let range = U::<2>::from_u16(3)..U::from_u16(7);
+        assert_eq!(
+            range.collect::<Vec<_>>(),
+            [3, 4, 5, 6].map(U::from_u16).to_vec()
+        );
+
+        let range = U::<2>::from_u16(0xFE)..=U::from_u16(0x101);
+        assert_eq!(
+            range.rev().collect::<Vec<_>>(),
+            [0x101, 0x100, 0xFF, 0xFE].map(U::from_u16).to_vec()
+        );
+
+        assert_eq!((U::<2>::from_u16(10)..U::from_u16(5)).count(), 0);
+        assert_eq!((U::<2>::zero()..=U::max_value()).count(), 0x10000);
+        assert_eq!((U::<2>::max_value()..=U::max_value()).count(), 1);
+    }
+
+    #[test]
+    fn test_checked_mul() {
+        let vals64 = [
+            0,
+            1,
+            2,
+            0xFFFF_FFFF,
+            0x1_0000_0000,
+            u64::MAX / 3,
+            u64::MAX / 2,
+            u64::MAX - 1,
+            u64::MAX,
+        ];
+        for &l in &vals64 {
+            for &r in &vals64 {
+                assert_eq!(
+                    U::from_u64(l).checked_mul(U::from_u64(r)).map(U::as_u64),
+                    l.checked_mul(r),
+                    "{l} * {r}",
+                );
+            }
+        }
+
+        let vals128 = [
+            0,
+            1,
+            3,
+            u128::from(u64::MAX),
+            1 << 64,
+            u128::MAX / 5,
+            u128::MAX / 2,
+            u128::MAX,
+        ];
+        for &l in &vals128 {
+            for &r in &vals128 {
+                assert_eq!(
+                    U::from_u128(l).checked_mul(U::from_u128(r)).map(U::as_u128),
+                    l.checked_mul(r),
+                    "{l} * {r}",
+                );
+            }
+        }
+
+        let mut big = [0; 32];
+        big[16] = 1;
+        let big = U::<32>::from_le_bytes(big);
+        assert!(big.checked_mul(big).is_none());
+        let mut two = [0; 32];
+        two[0] = 2;
+        let mut expected = [0; 32];
+        expected[16] = 2;
+        assert_eq!(
+            big.checked_mul(U::from_le_bytes(two)),
+            Some(U::from_le_bytes(expected))
+        );
+    }
+
     #[test]
     fn test_div() {
         let two = U([2, 0, 0]);
@@ -675,5 +1424,335 @@ mod tests {
         let ten = U([10, 0, 0]);
         assert_eq!(four / two, U([2, 0, 0]));
         assert_eq!(ten / two, U([5, 0, 0]));
+        assert_eq!(U([1, 2, 3]) / U([0, 1, 0]), U([2, 3, 0]));
+        assert_eq!(U([1, 2, 3]) % U([0, 1, 0]), U([1, 0, 0]));
+        assert_eq!(ten.checked_div(U::zero()), None);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "attempt to add with overflow"]
+    fn test_add_overflow() {
+        let _ = U::<2>::max_value() + U::from_u16(1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "attempt to multiply with overflow"]
+    fn test_mul_overflow() {
+        let _ = U::<2>::max_value() * U::from_u16(2);
+    }
+
+    #[test]
+    #[should_panic = "attempt to divide by zero"]
+    fn test_div_zero() {
+        let _ = U::<2>::from_u16(10) / U::zero();
+    }
+
+    #[test]
+    #[should_panic = "attempt to calculate the remainder with a divisor of zero"]
+    fn test_rem_zero() {
+        let _ = U::<2>::from_u16(10) % U::zero();
+    }
+
+    #[test]
+    fn test_carrying_add() {
+        let split = |val: u64| {
+            let bytes = val.to_le_bytes();
+            (
+                U::<4>::from_le_bytes(bytes[..4].try_into().unwrap()),
+                U::<4>::from_le_bytes(bytes[4..].try_into().unwrap()),
+            )
+        };
+        let join = |lo: U<4>, hi: U<4>| {
+            let mut bytes = [0; 8];
+            bytes[..4].copy_from_slice(&lo.to_le_bytes());
+            bytes[4..].copy_from_slice(&hi.to_le_bytes());
+            U::<8>::from_le_bytes(bytes)
+        };
+
+        let vals = [
+            0,
+            1,
+            0xFFFF_FFFF,
+            0x1_0000_0000,
+            0x1234_5678_9ABC_DEF0,
+            u64::MAX,
+        ];
+        for l in vals {
+            for r in vals {
+                let ((l_lo, l_hi), (r_lo, r_hi)) = (split(l), split(r));
+
+                let (lo, carry) = l_lo.carrying_add(r_lo, false);
+                let (hi, carry) = l_hi.carrying_add(r_hi, carry);
+                let (sum, overflow) = l.overflowing_add(r);
+                assert_eq!(join(lo, hi), U::from_u64(sum), "{l} + {r}");
+                assert_eq!(carry, overflow, "{l} + {r}");
+
+                let (lo, borrow) = l_lo.borrowing_sub(r_lo, false);
+                let (hi, borrow) = l_hi.borrowing_sub(r_hi, borrow);
+                let (diff, overflow) = l.overflowing_sub(r);
+                assert_eq!(join(lo, hi), U::from_u64(diff), "{l} - {r}");
+                assert_eq!(borrow, overflow, "{l} - {r}");
+            }
+        }
+
+        let max = U::<4>::max_value();
+        assert_eq!(max.carrying_add(max, true), (max, true));
+        assert_eq!(U::<4>::zero().borrowing_sub(max, true), (U::zero(), true));
+    }
+
+    #[test]
+    fn test_shift_prim() {
+        let one = U::<4>::from_u32(1);
+        assert_eq!(one << 9u32, U::from_u32(512));
+        assert_eq!(one << 31i32, U::from_u32(1 << 31));
+        assert_eq!(U::<4>::from_u32(0xF000) >> 12u32, U::from_u32(0xF));
+        assert_eq!(U::<4>::from_u32(0xF000) >> 4i32, U::from_u32(0xF00));
+    }
+
+    #[test]
+    fn test_shift_width() {
+        let one = U::<4>::from_u32(1);
+        assert_eq!(one << 31usize, U::from_u32(1 << 31));
+        assert_eq!(U::<4>::from_u32(1 << 31) >> 31usize, one);
+        // Widths that aren't a power of two shift correctly up to their last bit
+        assert_eq!(U::<3>::from_le_bytes([1, 0, 0]) << 23usize, U([0, 0, 0x80]));
+        assert_eq!(
+            U::<3>::from_le_bytes([0, 0, 0x80]) >> 20usize,
+            U([0x08, 0, 0])
+        );
+        assert_eq!(one << U::from_u32(4), U::from_u32(16));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "attempt to shift left with overflow"]
+    fn test_shl_overflow() {
+        let _ = U::<4>::from_u32(1) << 32usize;
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn test_shift_wrapping() {
+        let one = U::<4>::from_u32(1);
+        assert_eq!(one << 33usize, U::from_u32(2));
+        assert_eq!(U::<3>::from_le_bytes([1, 0, 0]) << 25usize, U([2, 0, 0]));
+        assert_eq!(
+            U::<16>::from_u128(1) << U::from_u128((1 << 100) + 3),
+            U::from_u128(8)
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "attempt to shift right with overflow"]
+    fn test_shr_overflow() {
+        let _ = U::<3>::from_le_bytes([1, 0, 0]) >> 24usize;
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic = "attempt to shift left with overflow"]
+    fn test_shl_overflow_wide_amount() {
+        let _ = U::<16>::from_u128(1) << U::from_u128(1 << 100);
+    }
+
+    #[test]
+    #[should_panic = "attempt to shift by a negative amount"]
+    fn test_shift_negative() {
+        let _ = U::<4>::from_u32(1) << -1i32;
+    }
+
+    #[test]
+    fn test_wrapping_neg() {
+        for val in [0u8, 1, 2, 127, 128, 255] {
+            assert_eq!(
+                U::<1>::from_u8(val).wrapping_neg(),
+                U::from_u8(val.wrapping_neg())
+            );
+        }
+        assert_eq!(U([0, 1]).wrapping_neg(), U([0, 0xFF]));
+    }
+
+    #[test]
+    fn test_gcd() {
+        let zero: U<2> = U::zero();
+        assert_eq!(zero.gcd(zero), zero);
+        assert_eq!(U([12, 0]).gcd(zero), U([12, 0]));
+        assert_eq!(U([48, 0]).gcd(U([18, 0])), U([6, 0]));
+        assert_eq!(U([18, 0]).gcd(U([48, 0])), U([6, 0]));
+        assert_eq!(U([0, 2]).gcd(U([0, 3])), U([0, 1]));
+        assert_eq!(U([17, 0]).gcd(U([5, 0])), U([1, 0]));
+    }
+
+    #[test]
+    fn test_lcm() {
+        let zero: U<2> = U::zero();
+        assert_eq!(zero.lcm(U([5, 0])), zero);
+        assert_eq!(U([4, 0]).lcm(U([6, 0])), U([12, 0]));
+        assert_eq!(U([21, 0]).lcm(U([6, 0])), U([42, 0]));
+    }
+
+    #[test]
+    fn test_to_str_radix() {
+        let val = U::<16>::from_le_bytes(0x0123_4567_89AB_CDEF_u128.to_le_bytes());
+        assert_eq!(val.to_str_radix(16), "123456789abcdef");
+        assert_eq!(val.to_str_radix(10), "81985529216486895");
+        assert_eq!(U::<1>::from_le_bytes([35]).to_str_radix(36), "z");
+        assert_eq!(U::<2>::from_le_bytes([0, 0]).to_str_radix(36), "0");
+        assert_eq!(U::<1>::from_le_bytes([5]).to_str_radix(2), "101");
+
+        for n in [0u128, 1, 35, 36, 1295, 0xDEAD_BEEF, u128::MAX] {
+            let val = U::<16>::from_le_bytes(n.to_le_bytes());
+            let str = val.to_str_radix(36);
+            assert_eq!(u128::from_str_radix(&str, 36), Ok(n));
+            assert_eq!(format!("{}", val.radix(36)), str);
+        }
+    }
+
+    #[test]
+    #[should_panic = "radix must be in the range 2..=36"]
+    fn test_to_str_radix_invalid() {
+        let _ = U::<4>::new().to_str_radix(37);
+    }
+
+    #[test]
+    fn test_wrapping_pow() {
+        for base in 0..=u8::MAX {
+            for exp in 0..20 {
+                assert_eq!(
+                    U::from_u8(base).wrapping_pow(exp).as_u8(),
+                    base.wrapping_pow(exp),
+                    "{base} ** {exp}",
+                );
+            }
+        }
+
+        let val = U::from_u64(3);
+        assert_eq!(val.wrapping_pow(41).as_u64(), 3u64.wrapping_pow(41));
+    }
+
+    #[test]
+    fn test_overflowing_pow() {
+        for base in 0..=u8::MAX {
+            for exp in 0..20 {
+                let (val, overflow) = U::from_u8(base).overflowing_pow(exp);
+                assert_eq!(
+                    (val.as_u8(), overflow),
+                    base.overflowing_pow(exp),
+                    "{base} ** {exp}",
+                );
+            }
+        }
+
+        let (val, overflow) = U::from_u64(2).overflowing_pow(63);
+        assert_eq!((val.as_u64(), overflow), (1 << 63, false));
+        let (val, overflow) = U::from_u64(2).overflowing_pow(64);
+        assert_eq!((val.as_u64(), overflow), (0, true));
+    }
+
+    #[test]
+    fn test_fmt() {
+        let val = U::from_u16(42);
+        assert_eq!(format!("{val}"), "42");
+        assert_eq!(format!("{val:?}"), "42");
+        assert_eq!(format!("{val:+}"), "+42");
+        assert_eq!(format!("{val:>5}"), "   42");
+        assert_eq!(format!("{val:<5}|"), "42   |");
+        assert_eq!(format!("{val:05}"), "00042");
+
+        assert_eq!(format!("{val:x}"), "2a");
+        assert_eq!(format!("{val:X}"), "2A");
+        assert_eq!(format!("{val:#06x}"), "0x002a");
+        assert_eq!(format!("{val:#X}"), "0x2A");
+        assert_eq!(format!("{val:o}"), "52");
+        assert_eq!(format!("{val:#o}"), "0o52");
+        assert_eq!(format!("{val:b}"), "101010");
+        assert_eq!(format!("{val:#010b}"), "0b00101010");
+
+        assert_eq!(
+            format!("{:x}", U::<16>::max_value()),
+            format!("{:x}", u128::MAX)
+        );
+        assert_eq!(format!("{:>8}", U::<2>::new().radix(36)), "       0");
+        // Binary of the largest value fills the whole digit buffer
+        assert_eq!(
+            format!("{:b}", U::<2>::max_value()),
+            format!("{:b}", u16::MAX)
+        );
+        assert_eq!(format!("{}", U::<1>::from_u8(0)), "0");
+    }
+
+    #[test]
+    fn test_overflowing() {
+        let max = U::<2>::max_value();
+        let one = U::<2>::one();
+        assert_eq!(max.overflowing_add(one), (U::new(), true));
+        assert_eq!(one.overflowing_add(one), (U::from_u16(2), false));
+        assert_eq!(U::<2>::new().overflowing_sub(one), (max, true));
+        assert_eq!(max.overflowing_sub(one), (U::from_u16(u16::MAX - 1), false));
+        assert_eq!(max.overflowing_mul(max), (one, true));
+        assert_eq!(
+            U::from_u16(300).overflowing_mul(U::from_u16(200)),
+            (U::from_u16(60000), false)
+        );
+    }
+
+    #[test]
+    fn test_assign_ops() {
+        let mut val = U::from_u16(10);
+        val += U::from_u16(5);
+        val *= U::from_u16(4);
+        val -= U::from_u16(6);
+        val /= U::from_u16(3);
+        assert_eq!(val, U::from_u16(18));
+        val %= U::from_u16(5);
+        assert_eq!(val, U::from_u16(3));
+        val |= U::from_u16(0x1C);
+        val &= U::from_u16(0x0F);
+        val ^= U::from_u16(0x01);
+        assert_eq!(val, U::from_u16(0x0E));
+    }
+
+    #[test]
+    fn test_bounded_bit() {
+        let val = U::<2>::from_u16(0x0010);
+        assert_eq!(BoundedBit::leading_zeros(val), U::from_u16(11));
+        assert_eq!(BoundedBit::trailing_zeros(val), U::from_u16(4));
+        assert_eq!(BoundedBit::leading_zeros(U::<2>::new()), U::from_u16(16));
+    }
+
+    #[test]
+    fn test_u_limbs() {
+        let left = [U::<4>::max_value(), U::from_u32(5)];
+        let right = [U::<4>::from_u32(1), U::from_u32(u32::MAX - 5)];
+        let sum = ElementAdd::add(&left, &right);
+        assert_eq!(sum, [U::new(), U::new(), U::one()]);
+
+        let mut left = left;
+        assert!(ElementAdd::add_checked(&mut left, &[U::from_u32(1)]).is_some());
+        assert_eq!(left, [U::new(), U::from_u32(6)]);
+    }
+
+    #[test]
+    fn test_widening_mul() {
+        let vals = [0, 1, 2, 15, 16, 100, 200, 254, u8::MAX];
+        for &l in &vals {
+            for &r in &vals {
+                for &a in &vals {
+                    let wide = u16::from(l) * u16::from(r) + u16::from(a);
+                    let (low, high) = U::from_u8(l).widening_mul(U::from_u8(r), U::from_u8(a));
+                    assert_eq!(
+                        u16::from_le_bytes([low.as_u8(), high.as_u8()]),
+                        wide,
+                        "{l} * {r} + {a}",
+                    );
+                }
+            }
+        }
+
+        let max = U::<4>::max_value();
+        assert_eq!(max.widening_mul(max, max), (U::new(), max));
     }
 }
@@ -1,16 +1,138 @@
 use super::U;
-use rand::distributions::Standard;
+use numeric_traits::identity::One;
+use numeric_traits::ops::wrapping::{WrappingAdd, WrappingSub};
+use rand::distr::uniform::{Error, SampleBorrow, SampleUniform, UniformSampler};
+use rand::distr::StandardUniform;
 use rand::prelude::{Distribution, Rng};
-use rand::{Error, Fill};
+use rand::Fill;
 
 impl<const N: usize> Fill for U<N> {
-    fn try_fill<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Result<(), Error> {
-        self.0.try_fill(rng)
+    fn fill<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.0.fill(rng)
     }
 }
 
-impl<const N: usize> Distribution<U<N>> for Standard {
+impl<const N: usize> Distribution<U<N>> for StandardUniform {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> U<N> {
-        U(Standard::sample(self, rng))
+        U::from_le_bytes(StandardUniform::sample(self, rng))
+    }
+}
+
+/// A sampler for uniformly distributed [`U<N>`] values within a range. This generates random
+/// bytes of the same bit length as the range, and rejects any that fall outside it, so there is no
+/// bias towards any part of the range.
+///
+/// This is usually used through [`Rng::random_range`], but can also be constructed directly to
+/// sample repeatedly from the same range.
+#[derive(Copy, Clone, Debug)]
+pub struct UniformU<const N: usize> {
+    low: U<N>,
+    /// The number of values in the range, or zero if the range covers every value
+    range: U<N>,
+}
+
+impl<const N: usize> UniformSampler for UniformU<N> {
+    type X = U<N>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (*low.borrow(), *high.borrow());
+        if low >= high {
+            return Err(Error::EmptyRange);
+        }
+        Ok(UniformU {
+            low,
+            range: high.wrapping_sub(low),
+        })
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (*low.borrow(), *high.borrow());
+        if low > high {
+            return Err(Error::EmptyRange);
+        }
+        Ok(UniformU {
+            low,
+            range: high.wrapping_sub(low).wrapping_add(U::one()),
+        })
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        let Some(top) = self.range.0.iter().rposition(|&b| b != 0) else {
+            // The range covers every value, so any bytes are valid
+            return rng.random();
+        };
+        let top_mask = u8::MAX >> self.range.0[top].leading_zeros();
+
+        loop {
+            let mut bytes = [0; N];
+            rng.fill(&mut bytes[..=top]);
+            bytes[top] &= top_mask;
+
+            let val = U::from_le_bytes(bytes);
+            if val < self.range {
+                return self.low.wrapping_add(val);
+            }
+        }
+    }
+}
+
+impl<const N: usize> SampleUniform for U<N> {
+    type Sampler = UniformU<N>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_range() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let low = U::<2>::from_u16(250);
+        let high = U::<2>::from_u16(260);
+
+        let mut seen = [false; 10];
+        for _ in 0..1000 {
+            let val = rng.random_range(low..high).as_u16();
+            assert!((250..260).contains(&val));
+            seen[usize::from(val - 250)] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn test_range_inclusive() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        let mut seen = [false; 256];
+        for _ in 0..10000 {
+            let val = rng
+                .random_range(U::<1>::from_u8(0)..=U::from_u8(255))
+                .as_u8();
+            seen[usize::from(val)] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+
+        for _ in 0..100 {
+            let val = rng.random_range(U::<2>::from_u16(7)..=U::from_u16(7));
+            assert_eq!(val.as_u16(), 7);
+        }
+    }
+
+    #[test]
+    fn test_empty_range() {
+        let val = U::<4>::from_u32(0x0100);
+        assert!(UniformU::new(val, val).is_err());
+        assert!(UniformU::new(val, U::from_u32(0x0001)).is_err());
+        assert!(UniformU::new_inclusive(val, val).is_ok());
     }
 }
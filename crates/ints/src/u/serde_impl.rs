@@ -0,0 +1,83 @@
+use super::U;
+use core::fmt;
+use core::marker::PhantomData;
+use serde::de::{Error, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub(crate) fn serialize_bytes<S: Serializer, const N: usize>(
+    bytes: &[u8; N],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut tuple = serializer.serialize_tuple(N)?;
+    for b in bytes {
+        tuple.serialize_element(b)?;
+    }
+    tuple.end()
+}
+
+pub(crate) fn deserialize_bytes<'de, D: Deserializer<'de>, const N: usize>(
+    deserializer: D,
+) -> Result<[u8; N], D::Error> {
+    struct BytesVisitor<const N: usize>(PhantomData<[u8; N]>);
+
+    impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+        type Value = [u8; N];
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "an array of {N} bytes")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[u8; N], A::Error> {
+            let mut out = [0; N];
+            for (idx, b) in out.iter_mut().enumerate() {
+                *b = seq
+                    .next_element()?
+                    .ok_or_else(|| A::Error::invalid_length(idx, &self))?;
+            }
+            if seq.next_element::<u8>()?.is_some() {
+                return Err(A::Error::invalid_length(N + 1, &self));
+            }
+            Ok(out)
+        }
+    }
+
+    deserializer.deserialize_tuple(N, BytesVisitor(PhantomData))
+}
+
+/// Serialized as its little-endian bytes, a fixed-length tuple of `N` `u8`s
+impl<const N: usize> Serialize for U<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_bytes(&self.0, serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for U<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_bytes(deserializer).map(U)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let val = U::<4>::from_u32(0x1234_5678);
+        let json = serde_json::to_string(&val).unwrap();
+        assert_eq!(json, "[120,86,52,18]");
+        assert_eq!(serde_json::from_str::<U<4>>(&json).unwrap(), val);
+
+        let max = U::<16>::from_u128(u128::MAX);
+        let json = serde_json::to_string(&max).unwrap();
+        assert_eq!(serde_json::from_str::<U<16>>(&json).unwrap(), max);
+    }
+
+    #[test]
+    fn test_wrong_length() {
+        assert!(serde_json::from_str::<U<4>>("[1,2,3]").is_err());
+        assert!(serde_json::from_str::<U<4>>("[1,2,3,4,5]").is_err());
+        assert!(serde_json::from_str::<U<1>>("[256]").is_err());
+    }
+}
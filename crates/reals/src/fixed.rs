@@ -5,15 +5,60 @@
 use core::cmp::Ordering;
 use core::fmt::{self, Write};
 use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
-use numeric_traits::cast::FromTruncating;
+use core::str::FromStr;
+use numeric_traits::cast::{FromSaturating, FromTruncating};
 use numeric_traits::class::{Bounded, BoundedSigned, Integral, Numeric, Real, Signed};
 use numeric_traits::identity::{One, Zero};
-use numeric_traits::ops::Pow;
+use numeric_traits::ops::checked::CheckedOps;
+use numeric_traits::ops::{Gcd, Pow};
+
+use crate::rat::Rat;
 
 fn mask<T: Integral, const N: usize>() -> T {
     (T::one() << N) - T::one()
 }
 
+/// Compute `a * b / d` as a quotient and remainder, for non-negative `a` and `b` and positive
+/// `d`. The product is long-divided one bit of `b` at a time, so nothing larger than the final
+/// quotient or `d` is ever formed.
+fn mul_div<T: Integral>(a: T, b: T, d: T) -> (T, T) {
+    let a_quot = a.clone() / d.clone();
+    let a_rem = a % d.clone();
+
+    let mut len = 0;
+    let mut bits = b.clone();
+    while !bits.is_zero() {
+        bits = bits >> 1;
+        len += 1;
+    }
+
+    let mut quot = T::zero();
+    let mut rem = T::zero();
+    for idx in (0..len).rev() {
+        // Doubling or adding to `rem` could overflow, so compare against the gap below `d`
+        quot = quot.clone() + quot;
+        let gap = d.clone() - rem.clone();
+        if rem >= gap {
+            rem = rem - gap;
+            quot = quot + T::one();
+        } else {
+            rem = rem.clone() + rem;
+        }
+
+        if !((b.clone() >> idx) & T::one()).is_zero() {
+            quot = quot + a_quot.clone();
+            let gap = d.clone() - a_rem.clone();
+            if rem >= gap {
+                rem = rem - gap;
+                quot = quot + T::one();
+            } else {
+                rem = rem + a_rem.clone();
+            }
+        }
+    }
+    (quot, rem)
+}
+
 /// A fixed-precision value. Given a backing integer T, uses its first `N` bits as decimal
 /// precision. If `T` is bounded, this value will also be bounded.
 #[derive(Copy, Clone)]
@@ -35,6 +80,134 @@ impl<T: Integral, const N: usize> Fixed<T, N> {
     fn is_whole(&self) -> bool {
         self.0.clone() & !mask::<T, N>() == self.0
     }
+
+    /// Apply `op` to the magnitudes of two values, then restore the sign of their product.
+    fn with_signs(self, rhs: Self, op: impl FnOnce(T, T) -> T) -> Self {
+        let zero = T::zero();
+        let neg = (self.0 < zero) != (rhs.0 < zero);
+        let abs = |val: T| {
+            if val < T::zero() {
+                T::zero() - val
+            } else {
+                val
+            }
+        };
+        let out = op(abs(self.0), abs(rhs.0));
+        Fixed(if neg { zero - out } else { out })
+    }
+
+    /// Multiply two values, keeping the result scaled by `2^N`. The full product is never formed,
+    /// so this only overflows if the result itself isn't representable. The result is rounded
+    /// towards zero.
+    fn mul_scaled(self, rhs: Self) -> Self {
+        self.with_signs(rhs, |a, b| mul_div(a, b, T::one() << N).0)
+    }
+
+    /// Divide two values, keeping the result scaled by `2^N`. The dividend is never shifted up,
+    /// so this only overflows if the result itself isn't representable. The result is rounded
+    /// towards zero.
+    fn div_scaled(self, rhs: Self) -> Self {
+        self.with_signs(rhs, |a, b| mul_div(a, T::one() << N, b).0)
+    }
+
+    /// Raise this value to an integer power, by repeated squaring. Every multiplication rounds
+    /// towards zero, so the error can grow with the number of squarings. Negative exponents divide
+    /// one by the positive power, rather than raising the rounded reciprocal, so that the
+    /// reciprocal's error isn't compounded as well.
+    ///
+    /// # Panics
+    ///
+    /// If this value is zero and `exp` is negative, or in debug mode if the positive power
+    /// overflows.
+    #[must_use]
+    pub fn pow_i32(self, exp: i32) -> Self {
+        let mut base = self;
+        let mut rest = exp.unsigned_abs();
+        let mut out = Self::one();
+        while rest > 0 {
+            if rest & 1 == 1 {
+                out = out.mul_scaled(base.clone());
+            }
+            rest >>= 1;
+            if rest > 0 {
+                base = base.clone().mul_scaled(base);
+            }
+        }
+        if exp < 0 {
+            Self::one().div_scaled(out)
+        } else {
+            out
+        }
+    }
+
+    /// Check whether this value is within `eps` of another. Useful for comparing the results of
+    /// approximate operations, such as [`Real::sqrt`], against an expected value. A negative
+    /// `eps` is never satisfied.
+    pub fn approx_eq(&self, other: &Self, eps: Self) -> bool {
+        let zero = T::zero();
+        if eps.0 < zero {
+            return false;
+        }
+        let (hi, lo) = if self.0 > other.0 {
+            (self.0.clone(), other.0.clone())
+        } else {
+            (other.0.clone(), self.0.clone())
+        };
+        if lo < zero && hi > zero {
+            // The difference of values with opposite signs may overflow, but adding a
+            // non-negative `eps` to the negative value never does
+            hi <= lo + eps.0
+        } else {
+            hi - lo <= eps.0
+        }
+    }
+}
+
+impl<T: Integral + Gcd<Output = T>, const N: usize> Fixed<T, N> {
+    /// Convert this value into a rational, with the raw value over `2^N` as the fraction. This is
+    /// always exact.
+    ///
+    /// # Panics
+    ///
+    /// If `2^N` doesn't fit in `T`
+    #[must_use]
+    pub fn to_rat(self) -> Rat<T> {
+        Rat::new(self.0, T::one() << N).expect("2^N should be representable by T")
+    }
+}
+
+impl<T, const N: usize> Fixed<T, N>
+where
+    T: Integral + FromSaturating<f64>,
+    f64: FromSaturating<T>,
+{
+    fn scale() -> f64 {
+        let mut scale = 1.0;
+        for _ in 0..N {
+            scale *= 2.0;
+        }
+        scale
+    }
+
+    /// Create a value from a float, rounding to the nearest representable value. Rounding is half
+    /// away from zero, and values outside the range of `T` saturate to its bounds.
+    ///
+    /// Any precision in `val` smaller than `2^-N` is lost.
+    pub fn from_f64(val: f64) -> Self {
+        let scaled = val * Self::scale();
+        let rounded = if scaled < 0.0 {
+            scaled - 0.5
+        } else {
+            scaled + 0.5
+        };
+        Fixed(T::saturate_from(rounded))
+    }
+
+    /// Convert this value into a float. This is exact as long as the backing value fits into the
+    /// 53-bit mantissa of an `f64`, otherwise the lowest bits are lost.
+    pub fn to_f64(self) -> f64 {
+        f64::saturate_from(self.0) / Self::scale()
+    }
 }
 
 impl<T: Integral, const N: usize> Default for Fixed<T, N> {
@@ -79,6 +252,80 @@ where
     }
 }
 
+/// The error for when you try to parse a [`Fixed`] from a string that isn't a valid decimal number
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromStrError {
+    /// The string contained no digits
+    Empty,
+    /// Character wasn't a decimal digit, or was a second decimal point
+    InvalidChar(char),
+    /// The value is outside the range the backing integer can represent
+    Overflow,
+}
+
+fn parse_digits(str: &str) -> Result<impl DoubleEndedIterator<Item = u8> + '_, FromStrError> {
+    match str.chars().find(|c| !c.is_ascii_digit()) {
+        Some(c) => Err(FromStrError::InvalidChar(c)),
+        None => Ok(str.bytes().map(|b| b - b'0')),
+    }
+}
+
+impl<T, const N: usize> FromStr for Fixed<T, N>
+where
+    T: Integral + FromTruncating<usize> + CheckedOps,
+{
+    type Err = FromStrError;
+
+    /// Parse a decimal string such as `-3.25`. The fractional part is optional. Fractional digits
+    /// beyond what can be represented in `N` bits are truncated, rounding the value towards zero.
+    /// Values outside the range of `T` produce [`FromStrError::Overflow`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (neg, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+        let (whole, fract) = s.split_once('.').unwrap_or((s, ""));
+        if whole.is_empty() && fract.is_empty() {
+            return Err(FromStrError::Empty);
+        }
+
+        // Negative values are accumulated downwards, so the minimum of a signed `T` still parses
+        let accumulate = |out: T, val: T| {
+            if neg {
+                out.checked_sub(val)
+            } else {
+                out.checked_add(val)
+            }
+        };
+
+        let ten = T::truncate_from(10);
+        let mut out = T::zero();
+        for d in parse_digits(whole)? {
+            out = out
+                .checked_mul(ten.clone())
+                .and_then(|out| accumulate(out, T::truncate_from(usize::from(d))))
+                .ok_or(FromStrError::Overflow)?;
+        }
+
+        // The fractional bits are `floor(digits * 2^N / 10^len)`. Dividing by ten once per digit,
+        // starting from the last, gives the same result with every intermediate below `2^N`.
+        // Splitting `2^N` as `10 * high + low` keeps `digit * 2^N` from being formed directly.
+        let scale = T::one() << N;
+        let high = scale.clone() / ten.clone();
+        let low = scale.clone() % ten.clone();
+        let mut bits = T::zero();
+        for d in parse_digits(fract)?.rev() {
+            let d = T::truncate_from(usize::from(d));
+            bits = d.clone() * high.clone() + (d * low.clone() + bits) / ten.clone();
+        }
+
+        out.checked_mul(scale)
+            .and_then(|out| accumulate(out, bits))
+            .map(Fixed)
+            .ok_or(FromStrError::Overflow)
+    }
+}
+
 impl<T, const N: usize> fmt::Binary for Fixed<T, N>
 where
     T: Integral + fmt::Binary,
@@ -267,6 +514,122 @@ impl<T: Integral, const N: usize> Real for Fixed<T, N> {
     fn log(self, base: Self) -> Self {
         todo!()
     }
+
+    /// Get the square root of this number, via Newton's method. The result is rounded towards
+    /// zero.
+    ///
+    /// # Panics
+    ///
+    /// If this value is negative
+    fn sqrt(self) -> Self {
+        assert!(
+            self >= Self::zero(),
+            "Cannot take the square root of a negative value"
+        );
+        if self.is_zero() {
+            return self;
+        }
+
+        let two = T::one() + T::one();
+        // Start from a value known to be at least the root, each step then moves strictly closer
+        // to it until we reach the closest representable value.
+        let mut guess = if self > Self::one() {
+            self.clone()
+        } else {
+            Self::one()
+        };
+        // The guess never exceeds `max(self, 1)`, and the average is taken as `quot + diff / 2`,
+        // so no step can overflow.
+        loop {
+            let quot = self.clone().div_scaled(guess.clone());
+            if quot >= guess {
+                return guess;
+            }
+            let next = Fixed(quot.0.clone() + (guess.0.clone() - quot.0) / two.clone());
+            if next >= guess {
+                return guess;
+            }
+            guess = next;
+        }
+    }
+
+    /// Get the cube root of this number, via Newton's method. The result is rounded towards zero,
+    /// and as with [`Real::sqrt`] no intermediate step can overflow.
+    fn cbrt(self) -> Self {
+        if self < Self::zero() {
+            return Self::zero() - (Self::zero() - self).cbrt();
+        } else if self.is_zero() {
+            return self;
+        }
+
+        let two = T::one() + T::one();
+        let three = two.clone() + T::one();
+        let mut guess = if self > Self::one() {
+            self.clone()
+        } else {
+            Self::one()
+        };
+        loop {
+            let quot = self
+                .clone()
+                .div_scaled(guess.clone())
+                .div_scaled(guess.clone());
+            if quot >= guess {
+                return guess;
+            }
+            // (2 * guess + quot) / 3, as `quot + 2 * diff / 3` so doubling can't overflow
+            let diff = guess.0.clone() - quot.0.clone();
+            let step = diff.clone() / three.clone() * two.clone()
+                + diff % three.clone() * two.clone() / three.clone();
+            let next = Fixed(quot.0 + step);
+            if next >= guess {
+                return guess;
+            }
+            guess = next;
+        }
+    }
+
+    /// Get the length of the hypotenuse of a right triangle with sides `self` and `other`, via
+    /// Newton's method. The result is rounded towards zero.
+    ///
+    /// The sum of squares is never formed, only divided by the current guess, so this can only
+    /// overflow if the result is within about 6% of the largest value `T` can represent.
+    fn hypot(self, other: Self) -> Self {
+        let abs = |val: T| {
+            if val < T::zero() {
+                T::zero() - val
+            } else {
+                val
+            }
+        };
+        let (a, b) = (abs(self.0), abs(other.0));
+        let (big, small) = if a >= b { (a, b) } else { (b, a) };
+        if big.is_zero() {
+            return Fixed(big);
+        }
+
+        let two = T::one() + T::one();
+        // The root of the raw values is already scaled correctly. One step from `big`, which is
+        // below the root, lands at or above it, and from there each step moves strictly closer.
+        let (step, _) = mul_div(small.clone(), small.clone(), big.clone());
+        let mut guess = big.clone() + step / two.clone();
+        loop {
+            let (big_quot, big_rem) = mul_div(big.clone(), big.clone(), guess.clone());
+            let (small_quot, small_rem) = mul_div(small.clone(), small.clone(), guess.clone());
+            let mut quot = big_quot + small_quot;
+            if big_rem >= guess.clone() - small_rem {
+                quot = quot + T::one();
+            }
+            if quot >= guess {
+                return Fixed(guess);
+            }
+            let next = quot.clone() + (guess.clone() - quot) / two.clone();
+            if next >= guess {
+                return Fixed(guess);
+            }
+            guess = next;
+        }
+    }
 }
 
 #[cfg(test)]
@@ -282,6 +645,125 @@ mod tests {
         assert_eq!(&format!("{:?}", Fixed::<_, 1>::from_raw(0b11)), "1.5");
     }
 
+    #[test]
+    fn fixed_f64() {
+        assert_eq!(
+            Fixed::<i32, 8>::from_f64(1.5),
+            Fixed::from_raw(0b1_1000_0000)
+        );
+        assert_eq!(Fixed::<i32, 8>::from_f64(1.5).to_f64(), 1.5);
+        assert_eq!(Fixed::<i32, 8>::from_f64(-2.25).to_f64(), -2.25);
+        assert_eq!(Fixed::<i32, 8>::from_val(3).to_f64(), 3.0);
+        // 0.1 isn't representable, rounds to the nearest 1/256
+        assert_eq!(Fixed::<i32, 8>::from_f64(0.1), Fixed::from_raw(26));
+        assert_eq!(Fixed::<i32, 8>::from_f64(-0.1), Fixed::from_raw(-26));
+        assert_eq!(Fixed::<i8, 4>::from_f64(100.0), Fixed::from_raw(i8::MAX));
+    }
+
+    #[test]
+    fn fixed_from_str() {
+        assert_eq!(
+            "1.5".parse::<Fixed<i32, 8>>(),
+            Ok(Fixed::from_raw(0b1_1000_0000))
+        );
+        assert_eq!(
+            "-2.25".parse::<Fixed<i32, 8>>(),
+            Ok(Fixed::from_raw(-0b10_0100_0000))
+        );
+        assert_eq!("12".parse::<Fixed<i32, 8>>(), Ok(Fixed::from_val(12)));
+        assert_eq!(".5".parse::<Fixed<i32, 8>>(), Ok(Fixed::from_raw(0x80)));
+        // 0.1 * 256 = 25.6, truncated to 25
+        assert_eq!("0.1".parse::<Fixed<i32, 8>>(), Ok(Fixed::from_raw(25)));
+        assert_eq!(
+            "0.99999999999".parse::<Fixed<i32, 8>>(),
+            Ok(Fixed::from_raw(0xFF))
+        );
+
+        assert_eq!("".parse::<Fixed<i32, 8>>(), Err(FromStrError::Empty));
+        assert_eq!("-.".parse::<Fixed<i32, 8>>(), Err(FromStrError::Empty));
+        assert_eq!(
+            "1.2.3".parse::<Fixed<i32, 8>>(),
+            Err(FromStrError::InvalidChar('.'))
+        );
+        assert_eq!(
+            "1a".parse::<Fixed<i32, 8>>(),
+            Err(FromStrError::InvalidChar('a'))
+        );
+    }
+
+    #[test]
+    fn fixed_from_str_range() {
+        // i32 with 8 fractional bits holds [-2^23, 2^23)
+        assert_eq!(
+            "8388607.99609375".parse::<Fixed<i32, 8>>(),
+            Ok(Fixed::from_raw(i32::MAX))
+        );
+        assert_eq!(
+            "-8388608".parse::<Fixed<i32, 8>>(),
+            Ok(Fixed::from_raw(i32::MIN))
+        );
+        assert_eq!(
+            "8388608".parse::<Fixed<i32, 8>>(),
+            Err(FromStrError::Overflow)
+        );
+        assert_eq!(
+            "-8388608.5".parse::<Fixed<i32, 8>>(),
+            Err(FromStrError::Overflow)
+        );
+        assert_eq!(
+            "100000000".parse::<Fixed<i32, 8>>(),
+            Err(FromStrError::Overflow)
+        );
+        assert_eq!(
+            "99999999999".parse::<Fixed<i32, 8>>(),
+            Err(FromStrError::Overflow)
+        );
+        assert_eq!("-1".parse::<Fixed<u32, 8>>(), Err(FromStrError::Overflow));
+        assert_eq!("-0".parse::<Fixed<u32, 8>>(), Ok(Fixed::zero()));
+    }
+
+    #[test]
+    fn fixed_pow_i32() {
+        let a = Fixed::<i32, 8>::from_f64(1.5);
+        assert_eq!(a.pow_i32(0), Fixed::one());
+        assert_eq!(a.pow_i32(1), a);
+        assert_eq!(a.pow_i32(2).to_f64(), 2.25);
+        assert_eq!(a.pow_i32(3).to_f64(), 3.375);
+        assert_eq!(Fixed::<i32, 8>::from_val(2).pow_i32(-2).to_f64(), 0.25);
+        assert_eq!(Fixed::<i32, 8>::from_val(-2).pow_i32(-1).to_f64(), -0.5);
+
+        type F = Fixed<i32, 16>;
+        assert_eq!(F::from_val(2).pow_i32(1), F::from_val(2));
+        assert_eq!(F::from_val(2).pow_i32(14), F::from_val(16384));
+        assert_eq!(F::from_val(-3).pow_i32(3), F::from_val(-27));
+        assert_eq!(F::from_f64(1.5).pow_i32(5).to_f64(), 7.59375);
+        assert_eq!(F::from_val(4).pow_i32(-3).to_f64(), 0.015625);
+        // Takes 31 squarings, rather than `i32::MAX` multiplications
+        assert_eq!(F::one().pow_i32(i32::MAX), F::one());
+        assert_eq!(F::from_f64(0.5).pow_i32(i32::MAX), F::zero());
+    }
+
+    #[test]
+    fn fixed_approx_eq() {
+        let a = Fixed::<i32, 8>::from_f64(1.5);
+        let eps = Fixed::from_raw(2);
+        assert!(a.approx_eq(&a, Fixed::zero()));
+        assert!(a.approx_eq(&Fixed::from_raw(a.0 + 2), eps));
+        assert!(a.approx_eq(&Fixed::from_raw(a.0 - 1), eps));
+        assert!(!a.approx_eq(&Fixed::from_raw(a.0 + 3), eps));
+        assert!(!a.approx_eq(&Fixed::from_f64(-1.5), eps));
+        assert!(!a.approx_eq(&a, Fixed::from_raw(-1)));
+
+        let half = Fixed::<i32, 8>::from_f64(0.5);
+        assert!(half.approx_eq(&-half, Fixed::one()));
+        assert!(!half.approx_eq(&-half, Fixed::from_f64(0.75)));
+        // The raw difference of these would overflow
+        let (max, min) = (Fixed::<i32, 8>::max_value(), Fixed::<i32, 8>::min_value());
+        assert!(!max.approx_eq(&min, eps));
+        assert!(!min.approx_eq(&max, max));
+        assert!(max.approx_eq(&max, eps));
+    }
+
     #[test]
     fn fixed_floor() {
         assert_eq!(Fixed::<_, 1>::from_val(2).floor(), Fixed::from_val(2));
@@ -319,4 +801,62 @@ mod tests {
             Fixed::from_raw(-0b010)
         );
     }
+
+    #[test]
+    fn fixed_sqrt() {
+        assert_eq!(Fixed::<i64, 16>::from_val(0).sqrt(), Fixed::from_val(0));
+        assert_eq!(Fixed::<i64, 16>::from_val(16).sqrt(), Fixed::from_val(4));
+        assert_eq!(
+            Fixed::<i64, 16>::from_raw(1 << 14).sqrt(),
+            Fixed::from_raw(1 << 15)
+        );
+        // sqrt(2) * 2^16 ~= 92681.9
+        let root_two = Fixed::<i64, 16>::from_val(2).sqrt().0;
+        assert!((root_two - 92682).abs() <= 1);
+    }
+
+    #[test]
+    fn fixed_cbrt() {
+        assert_eq!(Fixed::<i64, 16>::from_val(27).cbrt(), Fixed::from_val(3));
+        assert_eq!(Fixed::<i64, 16>::from_val(-8).cbrt(), Fixed::from_val(-2));
+        // cbrt(2) * 2^16 ~= 82570.6
+        let cbrt_two = Fixed::<i64, 16>::from_val(2).cbrt().0;
+        assert!((cbrt_two - 82571).abs() <= 1);
+    }
+
+    #[test]
+    fn fixed_hypot() {
+        let a = Fixed::<i64, 16>::from_val(3);
+        let b = Fixed::<i64, 16>::from_val(4);
+        assert_eq!(a.hypot(b), Fixed::from_val(5));
+        // hypot(1, 1) = sqrt(2)
+        let c = Fixed::<i64, 16>::one().hypot(Fixed::one()).0;
+        assert!((c - 92682).abs() <= 1);
+    }
+
+    #[test]
+    fn fixed_roots_narrow() {
+        type F = Fixed<i32, 16>;
+        assert_eq!(F::one().sqrt(), F::one());
+        assert_eq!(F::from_f64(4.0).sqrt(), F::from_val(2));
+        assert_eq!(F::one().cbrt(), F::one());
+        assert_eq!(F::from_val(27).cbrt(), F::from_val(3));
+        assert_eq!(F::from_val(3).hypot(F::from_val(4)), F::from_val(5));
+        assert_eq!(F::from_val(-3).hypot(F::from_val(4)), F::from_val(5));
+
+        // Near the top of the range: (2^31 - 1) / 2^16 ~= 32768, sqrt ~= 181.02
+        let big = F::from_raw(i32::MAX);
+        assert!(big
+            .sqrt()
+            .approx_eq(&F::from_f64(181.019_336), F::from_raw(2)));
+        assert!(big.cbrt().approx_eq(&F::from_f64(32.0), F::from_raw(2)));
+        assert!(F::from_val(20000)
+            .hypot(F::from_val(20000))
+            .approx_eq(&F::from_f64(28_284.271_247), F::from_raw(2)));
+
+        assert_eq!(
+            Fixed::<i32, 8>::from_f64(40000.0).sqrt(),
+            Fixed::from_val(200)
+        );
+    }
 }
@@ -5,10 +5,13 @@
 use core::cmp::Ordering;
 use core::fmt;
 use core::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use numeric_traits::cast::FromSaturating;
 use numeric_traits::class::{Bounded, BoundedSigned, Integral, Numeric, Real, Signed};
 use numeric_traits::identity::{One, Zero};
 use numeric_traits::ops::{Gcd, Pow};
 
+use crate::fixed::Fixed;
+
 /// A real value represented as a whole fraction. With a bounded
 /// backing type, this type can represent all whole values from the backing's maximum to minimum,
 /// half step values from half maximum to half minimum, etc.
@@ -53,14 +56,13 @@ impl<T: Integral> Rat<T> {
         T: Gcd<Output = T>,
     {
         if num == T::zero() {
-            Rat::zero()
-        } else if (num == T::one() && denom == T::one())
-            || num.clone() % denom.clone() == T::zero()
-            || denom.clone() % num.clone() == T::zero()
-        {
+            return Rat::zero();
+        }
+
+        let gcd = num.clone().gcd(denom.clone());
+        if gcd == T::one() {
             unsafe { Rat::new_unchecked(num, denom) }
         } else {
-            let gcd = num.clone().gcd(denom.clone());
             unsafe { Rat::new_unchecked(num / gcd.clone(), denom / gcd) }
         }
     }
@@ -77,15 +79,178 @@ impl<T: Integral> Rat<T> {
     pub fn into_pair(self) -> (T, T) {
         (self.num, self.denom)
     }
+
+    /// Raise this value to an integer power. Negative exponents raise the reciprocal of this
+    /// value instead.
+    ///
+    /// # Panics
+    ///
+    /// If this value is zero and `exp` is negative.
+    #[must_use]
+    pub fn pow_i32(self, exp: i32) -> Rat<T>
+    where
+        T: Gcd<Output = T>,
+    {
+        let mut base = if exp < 0 { self.recip() } else { self };
+        let mut exp = exp.unsigned_abs();
+        let mut out = Rat::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                out = out * base.clone();
+            }
+            exp >>= 1;
+            if exp > 0 {
+                base = base.clone() * base;
+            }
+        }
+        out
+    }
+
+    /// Convert this value into a float, by dividing the numerator by the denominator as floats
+    #[must_use]
+    pub fn to_f64(&self) -> f64
+    where
+        f64: FromSaturating<T>,
+    {
+        f64::saturate_from(self.num.clone()) / f64::saturate_from(self.denom.clone())
+    }
+
+    /// Convert this value into a fixed-precision value with `N` fractional bits. This is only
+    /// exact if the denominator is a power of two no larger than `2^N` - otherwise, the result is
+    /// truncated towards zero to the nearest multiple of `2^-N`.
+    #[must_use]
+    pub fn to_fixed<const N: usize>(self) -> Fixed<T, N> {
+        Fixed::from_raw((self.num << N) / self.denom)
+    }
+
+    fn recip(self) -> Rat<T> {
+        assert!(self.num != T::zero(), "Can't take the reciprocal of zero");
+        // Keep the sign on the numerator
+        if self.num < T::zero() {
+            unsafe { Rat::new_unchecked(T::zero() - self.denom, T::zero() - self.num) }
+        } else {
+            unsafe { Rat::new_unchecked(self.denom, self.num) }
+        }
+    }
+}
+
+impl<T> Rat<T>
+where
+    T: Integral + Gcd<Output = T> + FromSaturating<f64>,
+    f64: FromSaturating<T>,
+{
+    /// Find the best rational approximation of a float with a denominator no larger than
+    /// `max_denom`. This walks the continued fraction expansion of `val` up to the last convergent
+    /// whose denominator fits, then compares it against the largest semiconvergent that also
+    /// fits, returning whichever is closer.
+    ///
+    /// # Panics
+    ///
+    /// If `val` isn't finite, or `max_denom` is less than one.
+    #[must_use]
+    pub fn from_f64(val: f64, max_denom: T) -> Rat<T> {
+        assert!(val.is_finite(), "Can't approximate a non-finite value");
+        assert!(
+            max_denom >= T::one(),
+            "Maximum denominator must be positive"
+        );
+
+        let neg = val < 0.0;
+        let target = if neg { -val } else { val };
+        let mut x = target;
+
+        // The previous two convergents, starting from the identities `1/0` and `0/1`
+        let (mut num1, mut num2) = (T::one(), T::zero());
+        let (mut denom1, mut denom2) = (T::zero(), T::one());
+
+        loop {
+            // `x` is always positive, so truncation is the floor
+            let term = T::saturate_from(x);
+
+            // Check the next denominator against the bound without calculating it, as that
+            // could overflow
+            if denom1 != T::zero() && term > (max_denom.clone() - denom2.clone()) / denom1.clone() {
+                // The largest semiconvergent that fits may still be closer than the last
+                // convergent, so take whichever has the smaller error
+                let k = (max_denom - denom2.clone()) / denom1.clone();
+                if k > T::zero() {
+                    let semi_num = k.clone() * num1.clone() + num2;
+                    let semi_denom = k * denom1.clone() + denom2;
+                    let error = |num: &T, denom: &T| {
+                        (target
+                            - f64::saturate_from(num.clone()) / f64::saturate_from(denom.clone()))
+                        .abs()
+                    };
+                    if error(&semi_num, &semi_denom) < error(&num1, &denom1) {
+                        (num1, denom1) = (semi_num, semi_denom);
+                    }
+                }
+                break;
+            }
+            let num = term.clone() * num1.clone() + num2;
+            let denom = term.clone() * denom1.clone() + denom2;
+            (num2, num1) = (num1, num);
+            (denom2, denom1) = (denom1, denom);
+
+            let fract = x - f64::saturate_from(term);
+            if fract <= 0.0 {
+                break;
+            }
+            x = 1.0 / fract;
+        }
+
+        let num1 = if neg { T::zero() - num1 } else { num1 };
+        Rat::reduce(num1, denom1)
+    }
 }
 
 impl<T: Integral + fmt::Debug> fmt::Debug for Rat<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // TODO: Print as decimal
         write!(f, "{:?} / {:?}", self.num, self.denom)
     }
 }
 
+/// Displays the decimal expansion of the value, calculated by long division. With a precision,
+/// exactly that many decimal places are printed. Otherwise, printing stops once the expansion
+/// terminates, or after 10 places. Either way, any further digits are truncated rather than
+/// rounded.
+impl<T: Integral + fmt::Display> fmt::Display for Rat<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let abs = |val: &T| {
+            if *val < T::zero() {
+                T::zero() - val.clone()
+            } else {
+                val.clone()
+            }
+        };
+        let neg = (self.num < T::zero()) != (self.denom < T::zero());
+        let (num, denom) = (abs(&self.num), abs(&self.denom));
+        let ten = (0..10).fold(T::zero(), |acc, _| acc + T::one());
+
+        if neg {
+            f.write_str("-")?;
+        }
+        write!(f, "{}", num.clone() / denom.clone())?;
+
+        let mut rem = num % denom.clone();
+        let places = f.precision().unwrap_or(10);
+        if places == 0 || (f.precision().is_none() && rem == T::zero()) {
+            return Ok(());
+        }
+
+        f.write_str(".")?;
+        for _ in 0..places {
+            if f.precision().is_none() && rem == T::zero() {
+                break;
+            }
+            rem = rem * ten.clone();
+            write!(f, "{}", rem.clone() / denom.clone())?;
+            rem = rem % denom.clone();
+        }
+        Ok(())
+    }
+}
+
 impl<T: Integral> Default for Rat<T> {
     fn default() -> Self {
         Rat::zero()
@@ -285,6 +450,106 @@ mod tests {
         assert_eq!(a + b, Rat::new(5, 4).unwrap());
     }
 
+    #[test]
+    fn test_reduce_multiple() {
+        assert_eq!(Rat::new(2, 4).unwrap().into_pair(), (1, 2));
+        assert_eq!(Rat::new(128, 256).unwrap().into_pair(), (1, 2));
+        assert_eq!(Rat::new(6, 3).unwrap().into_pair(), (2, 1));
+        assert_eq!(Rat::new(-4, 2).unwrap().into_pair(), (-2, 1));
+        assert_eq!(Rat::new(5, 5).unwrap().into_pair(), (1, 1));
+    }
+
+    #[test]
+    fn test_pow_i32() {
+        let a = Rat::new(2, 3).unwrap();
+        assert_eq!(a.pow_i32(-1), Rat::new(3, 2).unwrap());
+        assert_eq!(a.pow_i32(0), Rat::one());
+        assert_eq!(a.pow_i32(3), Rat::new(8, 27).unwrap());
+        assert_eq!(a.pow_i32(-2), Rat::new(9, 4).unwrap());
+
+        let b = Rat::new(-1, 2).unwrap();
+        assert_eq!(b.pow_i32(-1), Rat::new(-2, 1).unwrap());
+        assert_eq!(b.pow_i32(-3), Rat::new(-8, 1).unwrap());
+        assert_eq!(b.pow_i32(2), Rat::new(1, 4).unwrap());
+    }
+
+    #[test]
+    #[should_panic = "Can't take the reciprocal of zero"]
+    fn test_pow_i32_zero() {
+        let _ = Rat::<i32>::zero().pow_i32(-1);
+    }
+
+    #[test]
+    fn test_from_f64() {
+        assert_eq!(Rat::from_f64(0.5, 100), Rat::new(1, 2).unwrap());
+        let pi = core::f64::consts::PI;
+        assert_eq!(Rat::from_f64(pi, 1000), Rat::new(355, 113).unwrap());
+        assert_eq!(Rat::from_f64(pi, 100), Rat::new(311, 99).unwrap());
+        assert_eq!(Rat::from_f64(pi, 1), Rat::new(3, 1).unwrap());
+        assert_eq!(
+            Rat::from_f64(-pi, 50_000),
+            Rat::new(-104348, 33215).unwrap()
+        );
+        assert_eq!(Rat::from_f64(-0.75, 100), Rat::new(-3, 4).unwrap());
+        assert_eq!(Rat::from_f64(0.1, 1000), Rat::new(1, 10).unwrap());
+        assert_eq!(Rat::from_f64(2.0, 10), Rat::new(2, 1).unwrap());
+        assert_eq!(Rat::from_f64(0.0, 10), Rat::zero());
+        assert_eq!(Rat::from_f64(1.0 / 3.0, i64::MAX), Rat::new(1, 3).unwrap());
+    }
+
+    #[test]
+    #[should_panic = "Can't approximate a non-finite value"]
+    fn test_from_f64_nan() {
+        let _ = Rat::from_f64(f64::NAN, 10);
+    }
+
+    #[test]
+    fn test_to_f64() {
+        assert_eq!(Rat::new(1, 4).unwrap().to_f64(), 0.25);
+        assert_eq!(Rat::new(-3, 2).unwrap().to_f64(), -1.5);
+        assert_eq!(Rat::new(1, 3).unwrap().to_f64(), 1.0 / 3.0);
+        assert_eq!(Rat::<i32>::zero().to_f64(), 0.0);
+    }
+
+    #[test]
+    fn test_fixed_round_trip() {
+        let vals = [(1, 2), (-5, 4), (15, 4), (3, 256), (-7, 1), (0, 1)];
+        for (num, denom) in vals {
+            let rat = Rat::new(num, denom).unwrap();
+            let fixed = rat.to_fixed::<8>();
+            assert_eq!(fixed.to_f64(), rat.to_f64());
+            assert_eq!(fixed.to_rat(), rat);
+        }
+
+        // Precision past `2^-N` is truncated
+        let third = Rat::new(1, 3).unwrap().to_fixed::<8>();
+        assert_eq!(third, Fixed::from_raw(85));
+        assert_eq!(third.to_rat(), Rat::new(85, 256).unwrap());
+        assert_eq!(
+            Rat::new(-1, 3).unwrap().to_fixed::<8>(),
+            Fixed::from_raw(-85)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        extern crate alloc;
+        use alloc::format;
+
+        let third = Rat::new(1, 3).unwrap();
+        assert_eq!(format!("{}", third), "0.3333333333");
+        assert_eq!(format!("{:.3}", third), "0.333");
+        assert_eq!(format!("{:.3}", Rat::new(2, 3).unwrap()), "0.666");
+        assert_eq!(format!("{:.0}", Rat::new(5, 3).unwrap()), "1");
+
+        assert_eq!(format!("{}", Rat::new(1, 4).unwrap()), "0.25");
+        assert_eq!(format!("{}", Rat::new(-7, 4).unwrap()), "-1.75");
+        assert_eq!(format!("{}", Rat::new(-1, 8).unwrap()), "-0.125");
+        assert_eq!(format!("{}", Rat::new(5, 1).unwrap()), "5");
+        assert_eq!(format!("{:.2}", Rat::new(5, 1).unwrap()), "5.00");
+        assert_eq!(format!("{}", Rat::<i32>::zero()), "0");
+    }
+
     #[test]
     fn test_round() {
         let a = Rat::new(3, 7).unwrap();
@@ -296,4 +561,19 @@ mod tests {
         assert_eq!(c.round(), Rat::one());
         assert_eq!(d.round(), Rat::one() + Rat::one());
     }
+
+    #[test]
+    fn test_reduce_bounded() {
+        use numeric_ints::I;
+
+        let i = |val: i32| I::<4>::from_le_bytes(val.to_le_bytes());
+
+        let a = Rat::new(i(6), i(8)).unwrap();
+        assert_eq!(a.into_pair(), (i(3), i(4)));
+        let b = Rat::new(i(-6), i(8)).unwrap();
+        assert_eq!(b.into_pair(), (i(-3), i(4)));
+        let c = Rat::new(i(300), i(-500)).unwrap();
+        assert_eq!(c.into_pair(), (i(3), i(-5)));
+        assert!(Rat::new(i(1), i(0)).is_none());
+    }
 }
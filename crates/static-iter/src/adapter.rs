@@ -51,3 +51,81 @@ where
         (idx, self.inner.idx(idx))
     }
 }
+
+/// Adapter mapping each item of an `N` item iterator to `K` items, producing an iterator of
+/// `M = N * K` items. Using any other length fails to compile.
+pub struct FlatMap<I, F, C, const N: usize> {
+    pub(crate) inner: I,
+    pub(crate) func: F,
+    pub(crate) chunk: Option<C>,
+}
+
+struct FlatMapLen<const N: usize, const K: usize, const M: usize>;
+
+impl<const N: usize, const K: usize, const M: usize> FlatMapLen<N, K, M> {
+    const VALID: () = assert!(
+        matches!(N.checked_mul(K), Some(len) if len == M),
+        "FlatMap output length must be the input length times the chunk length"
+    );
+}
+
+impl<I, F, T, const N: usize, const K: usize, const M: usize> StaticIter<M>
+    for FlatMap<I, F, core::array::IntoIter<T, K>, N>
+where
+    I: StaticIter<N>,
+    F: FnMut(I::Item) -> [T; K],
+{
+    type Item = T;
+
+    #[inline]
+    unsafe fn idx(&mut self, idx: usize) -> Self::Item {
+        let () = FlatMapLen::<N, K, M>::VALID;
+        if idx.is_multiple_of(K) {
+            // SAFETY: Indices are visited in order, so each index of the inner iterator is
+            //         requested once, at the start of its chunk. `idx < N * K`, so `idx / K < N`.
+            self.chunk = Some((self.func)(self.inner.idx(idx / K)).into_iter());
+        }
+        // SAFETY: The current chunk was created at most `K - 1` indices ago, so still has items
+        self.chunk
+            .as_mut()
+            .and_then(Iterator::next)
+            .unwrap_unchecked()
+    }
+}
+
+/// Adapter yielding all the items of an `N` item iterator, followed by all the items of an `M`
+/// item iterator, producing an iterator of `L = N + M` items. Using any other length fails to
+/// compile.
+pub struct Chain<I1, I2, const N: usize, const M: usize> {
+    pub(crate) first: I1,
+    pub(crate) second: I2,
+}
+
+struct ChainLen<const N: usize, const M: usize, const L: usize>;
+
+impl<const N: usize, const M: usize, const L: usize> ChainLen<N, M, L> {
+    const VALID: () = assert!(
+        matches!(N.checked_add(M), Some(len) if len == L),
+        "Chain output length must be the sum of the input lengths"
+    );
+}
+
+impl<I1, I2, const N: usize, const M: usize, const L: usize> StaticIter<L> for Chain<I1, I2, N, M>
+where
+    I1: StaticIter<N>,
+    I2: StaticIter<M, Item = I1::Item>,
+{
+    type Item = I1::Item;
+
+    #[inline]
+    unsafe fn idx(&mut self, idx: usize) -> Self::Item {
+        let () = ChainLen::<N, M, L>::VALID;
+        // SAFETY: Indices `0..N` map to the same indices of the first iterator, and `N..N + M` to
+        //         `0..M` of the second, preserving order and visiting each at most once.
+        if idx < N {
+            self.first.idx(idx)
+        } else {
+            self.second.idx(idx - N)
+        }
+    }
+}
@@ -1,6 +1,6 @@
 #![no_std]
 
-use adapter::{Enumerate, Map, Zip};
+use adapter::{Chain, Enumerate, FlatMap, Map, Zip};
 use core::convert::Infallible;
 use core::mem;
 use core::mem::MaybeUninit;
@@ -141,6 +141,49 @@ pub trait StaticIter<const N: usize>: Sized {
         }
     }
 
+    /// Follow the items of this iterator with the items of another, producing an iterator of
+    /// `N + M` items. As with [`StaticIter::flat_map`], the output length is checked at compile
+    /// time. It can only be inferred from where the items end up, so calling other adapters on
+    /// the result needs the length written out, as in `StaticIter::<3>::enumerate(iter)`.
+    ///
+    /// ```compile_fail
+    /// # use numeric_static_iter::{IntoStaticIter, StaticIter};
+    /// let _: [i32; 3] = [1, 2].into_static_iter().chain([3, 4]).collect();
+    /// ```
+    #[inline]
+    fn chain<I, const M: usize>(self, other: I) -> Chain<Self, I::Iter, N, M>
+    where
+        I: IntoStaticIter<M, Item = Self::Item>,
+    {
+        Chain {
+            first: self,
+            second: other.into_static_iter(),
+        }
+    }
+
+    /// Map each item to an array of `K` items, flattening the results into an iterator of
+    /// `N * K` items. The output length is checked at compile time, as it can't be written as
+    /// `N * K` on stable.
+    ///
+    /// ```compile_fail
+    /// # use numeric_static_iter::{IntoStaticIter, StaticIter};
+    /// let _: [i32; 3] = [1, 2].into_static_iter().flat_map(|x| [x, x]).collect();
+    /// ```
+    #[inline]
+    fn flat_map<T, F, const K: usize>(
+        self,
+        func: F,
+    ) -> FlatMap<Self, F, core::array::IntoIter<T, K>, N>
+    where
+        F: FnMut(Self::Item) -> [T; K],
+    {
+        FlatMap {
+            inner: self,
+            func,
+            chunk: None,
+        }
+    }
+
     #[inline]
     fn enumerate(self) -> Enumerate<Self> {
         Enumerate { inner: self }
@@ -173,6 +216,46 @@ pub trait StaticIter<const N: usize>: Sized {
         C::from_static_iter(self)
     }
 
+    /// Split an iterator of pairs into two arrays, in a single pass. If the iterator panics, any
+    /// items already produced are dropped.
+    fn unzip<A, B>(mut self) -> ([A; N], [B; N])
+    where
+        Self: StaticIter<N, Item = (A, B)>,
+    {
+        /// Drops the written prefix of an array if the unzip panics partway through
+        struct Partial<T, const N: usize> {
+            arr: [MaybeUninit<T>; N],
+            len: usize,
+        }
+
+        impl<T, const N: usize> Drop for Partial<T, N> {
+            fn drop(&mut self) {
+                for item in &mut self.arr[..self.len] {
+                    // SAFETY: The first `len` items have been written
+                    unsafe { item.assume_init_drop() };
+                }
+            }
+        }
+
+        let mut right = Partial::<B, N> {
+            arr: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        };
+        let left = core::array::from_fn(|idx| {
+            // SAFETY: `from_fn` calls exactly once for each value from `0..N`, in order
+            let (a, b) = unsafe { self.idx(idx) };
+            right.arr[idx].write(b);
+            right.len += 1;
+            a
+        });
+
+        let right = mem::ManuallyDrop::new(right);
+        // SAFETY: All `N` items have been written, and `[MaybeUninit<B>; N]` has the same layout
+        //         as `[B; N]`. `right` won't be dropped, so the items now belong to the result.
+        let right = unsafe { mem::transmute_copy(&right.arr) };
+        (left, right)
+    }
+
     fn any<F: FnMut(Self::Item) -> bool>(self, mut func: F) -> bool {
         self.try_fold((), |(), x| if func(x) { Err(()) } else { Ok(()) }) == Err(())
     }
@@ -256,6 +339,80 @@ mod tests {
         assert_eq!(res, [6, 8, 10, 12]);
     }
 
+    #[test]
+    fn test_unzip() {
+        let (nums, chars) = [(1, 'a'), (2, 'b')].into_static_iter().unzip();
+        assert_eq!(nums, [1, 2]);
+        assert_eq!(chars, ['a', 'b']);
+
+        let (left, right): ([i32; 0], [i32; 0]) = [].into_static_iter().unzip();
+        assert_eq!((left, right), ([], []));
+    }
+
+    #[test]
+    fn test_unzip_panic() {
+        extern crate std;
+        use core::cell::Cell;
+        use std::panic::{self, AssertUnwindSafe};
+
+        struct Counted<'a>(&'a Cell<usize>);
+
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+            [0, 1, 2, 3]
+                .into_static_iter()
+                .map(|idx| {
+                    assert_ne!(idx, 2, "unzip source panicked");
+                    (Counted(&drops), Counted(&drops))
+                })
+                .unzip()
+        }));
+        assert!(res.is_err());
+        // Both halves of the two pairs produced before the panic were dropped
+        assert_eq!(drops.get(), 4);
+    }
+
+    #[test]
+    fn test_chain() {
+        let res: [i32; 4] = [1, 2].into_static_iter().chain([3, 4]).collect();
+        assert_eq!(res, [1, 2, 3, 4]);
+
+        let res: [i32; 5] = [1].into_static_iter().chain([2, 3, 4, 5]).collect();
+        assert_eq!(res, [1, 2, 3, 4, 5]);
+
+        let res: [i32; 2] = [].into_static_iter().chain([1, 2]).collect();
+        assert_eq!(res, [1, 2]);
+
+        // The output length can't be inferred through further adapters
+        let chain = [1, 2].into_static_iter().chain([3]);
+        let res: [(usize, i32); 3] = StaticIter::<3>::enumerate(chain).collect();
+        assert_eq!(res, [(0, 1), (1, 2), (2, 3)]);
+    }
+
+    #[test]
+    fn test_flat_map() {
+        let res: [i32; 4] = [1, 2].into_static_iter().flat_map(|x| [x, x]).collect();
+        assert_eq!(res, [1, 1, 2, 2]);
+
+        let res: [(usize, char); 6] = ['a', 'b']
+            .into_static_iter()
+            .flat_map(|c| [0, 1, 2].map(|i| (i, c)))
+            .collect();
+        assert_eq!(
+            res,
+            [(0, 'a'), (1, 'a'), (2, 'a'), (0, 'b'), (1, 'b'), (2, 'b')]
+        );
+
+        let res: [u8; 0] = [1u8, 2, 3].into_static_iter().flat_map(|_| []).collect();
+        assert_eq!(res, []);
+    }
+
     #[test]
     fn test_option_collect() {
         let res: [u32; 4] = [1u32, 2, 3, 4]
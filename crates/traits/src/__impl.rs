@@ -166,6 +166,18 @@ macro_rules! impl_int {
             }
         }
 
+        impl crate::ops::Lcm for $ty {
+            type Output = $ty;
+
+            fn lcm(self, rhs: Self) -> Self::Output {
+                if self == 0 || rhs == 0 {
+                    return 0;
+                }
+
+                (self / crate::ops::Gcd::gcd(self, rhs)) * rhs
+            }
+        }
+
         impl crate::ops::wrapping::WrappingAdd for $ty {
             type Output = $ty;
 
@@ -206,6 +218,14 @@ macro_rules! impl_int {
             }
         }
 
+        impl crate::ops::wrapping::WrappingNeg for $ty {
+            type Output = $ty;
+
+            fn wrapping_neg(self) -> Self::Output {
+                <$ty>::wrapping_neg(self)
+            }
+        }
+
         impl crate::ops::overflowing::OverflowingAdd for $ty {
             type Output = $ty;
 
@@ -262,6 +282,14 @@ macro_rules! impl_int {
             }
         }
 
+        impl crate::ops::checked::CheckedRem for $ty {
+            type Output = $ty;
+
+            fn checked_rem(self, rhs: Self) -> Option<Self> {
+                <$ty>::checked_rem(self, rhs)
+            }
+        }
+
         impl crate::ops::checked::CheckedShl for $ty {
             type Output = $ty;
 
@@ -414,6 +442,14 @@ macro_rules! impl_float {
             fn sqrt(self) -> Self {
                 <$ty>::sqrt(self)
             }
+
+            fn cbrt(self) -> Self {
+                <$ty>::cbrt(self)
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                <$ty>::hypot(self, other)
+            }
         }
 
         impl crate::class::RealSigned for $ty {}
@@ -498,6 +534,32 @@ macro_rules! impl_float {
             fn cot(self) -> Self {
                 1. / self.tan()
             }
+
+            fn asin(self) -> Self {
+                <$ty>::asin(self)
+            }
+
+            fn acos(self) -> Self {
+                <$ty>::acos(self)
+            }
+
+            fn atan(self) -> Self {
+                <$ty>::atan(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                <$ty>::atan2(self, other)
+            }
+        }
+
+        impl crate::ops::ExpOps for $ty {
+            fn exp(self) -> Self {
+                <$ty>::exp(self)
+            }
+
+            fn ln(self) -> Self {
+                <$ty>::ln(self)
+            }
         }
 
         saturating_as!($ty, u8);
@@ -956,7 +1018,7 @@ mod nz;
 
 #[cfg(test)]
 mod tests {
-    use crate::ops::Gcd;
+    use crate::ops::{Gcd, Lcm};
 
     #[test]
     fn test_gcd() {
@@ -965,4 +1027,24 @@ mod tests {
         assert_eq!(48.gcd(18), 6);
         assert_eq!(18.gcd(48), 6);
     }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_real() {
+        use crate::class::Real;
+
+        assert_eq!(Real::hypot(3.0f64, 4.0), 5.0);
+        assert_eq!(Real::hypot(5.0f32, 12.0), 13.0);
+        assert_eq!(Real::cbrt(27.0f64), 3.0);
+        assert_eq!(Real::cbrt(-8.0f32), -2.0);
+    }
+
+    #[test]
+    fn test_lcm() {
+        assert_eq!(0.lcm(5), 0);
+        assert_eq!(5.lcm(0), 0);
+
+        assert_eq!(4.lcm(6), 12);
+        assert_eq!(21.lcm(6), 42);
+    }
 }
@@ -78,6 +78,12 @@ pub trait Real: Numeric {
             Self::one() / (Self::one() + Self::one() + Self::one()),
         )
     }
+
+    /// The length of the hypotenuse of a right-angle triangle with sides of length `self` and
+    /// `other`. Equivalent to `sqrt(self² + other²)`
+    fn hypot(self, other: Self) -> Self {
+        (self.clone() * self + other.clone() * other).sqrt()
+    }
 }
 
 /// Trait for types that are both [`Real`] and [`Signed`].
@@ -129,6 +135,29 @@ pub trait Bounded {
     fn max_value() -> Self;
 }
 
+/// Trait for types that may or may not be bounded. This allows generic code to work over both
+/// fixed-size and arbitrary-precision types, checking whether a limit exists before relying on it.
+///
+/// This is implemented for all [`Bounded`] types, returning their bounds. Unbounded types return
+/// `None`.
+pub trait MaybeBounded: Sized {
+    /// The minimum representable value for this type, if there is one
+    fn try_min_value() -> Option<Self>;
+
+    /// The maximum representable value for this type, if there is one
+    fn try_max_value() -> Option<Self>;
+}
+
+impl<T: Bounded> MaybeBounded for T {
+    fn try_min_value() -> Option<Self> {
+        Some(T::min_value())
+    }
+
+    fn try_max_value() -> Option<Self> {
+        Some(T::max_value())
+    }
+}
+
 /// Trait for types that are bounded and signed, and as such users may want to distinguish between
 /// their minimum value, and their minimum positive value.
 pub trait BoundedSigned: Bounded + Signed {
@@ -40,12 +40,29 @@ pub trait Gcd<Rhs = Self> {
     fn gcd(self, other: Rhs) -> Self::Output;
 }
 
+/// The least-common-multiple operation. Gets the smallest integer `N` such that for `a` and `b`,
+/// `N / a` and `N / b` are integers. The least-common-multiple of zero and any value is zero.
+pub trait Lcm<Rhs = Self> {
+    /// The type produced by applying this operation
+    type Output;
+
+    /// Get the least-common-multiple of this value and another
+    fn lcm(self, other: Rhs) -> Self::Output;
+}
+
 /// The common trigonometric operators. These can be understood geometrically as
 /// various values for a given angle in relation to the unit circle (a circle of radius 1).
 /// Each of the common functions has an
 ///
 /// The following is a graphical representation of the various trigonometric functions on a circle:
 /// ![](https://upload.wikimedia.org/wikipedia/commons/4/46/Unit_Circle_Definitions_of_Six_Trigonometric_Functions.svg)
+///
+/// # Breaking Change
+///
+/// The inverse functions [`TrigOps::asin`], [`TrigOps::acos`], [`TrigOps::atan`], and
+/// [`TrigOps::atan2`] were added as required methods, so existing implementations must now
+/// provide them. They have no default, as this trait has no access to a square root, `π`, or
+/// comparisons to build them from.
 pub trait TrigOps {
     /// The `sine` function.
     ///
@@ -104,6 +121,32 @@ pub trait TrigOps {
     /// The length where the ray from the center of the unit circle to its edge at a given angle has
     /// a Y value equal to 1.
     fn cot(self) -> Self;
+
+    /// The `arcsine` function, the inverse of `sine`. Gets the angle with a given `sine` value,
+    /// in the range `[-π/2, π/2]`.
+    fn asin(self) -> Self;
+
+    /// The `arccosine` function, the inverse of `cosine`. Gets the angle with a given `cosine`
+    /// value, in the range `[0, π]`.
+    fn acos(self) -> Self;
+
+    /// The `arctangent` function, the inverse of `tangent`. Gets the angle with a given `tangent`
+    /// value, in the range `[-π/2, π/2]`.
+    fn atan(self) -> Self;
+
+    /// The four-quadrant `arctangent` of `self / other`, treating `self` as the Y and `other` as
+    /// the X value of a point. Gets the angle from the positive X axis to that point, in the range
+    /// `[-π, π]`.
+    fn atan2(self, other: Self) -> Self;
+}
+
+/// The exponential function and its inverse, the natural logarithm.
+pub trait ExpOps {
+    /// Raise `e` to the power of this number
+    fn exp(self) -> Self;
+
+    /// The natural logarithm of this number, the inverse of [`ExpOps::exp`]
+    fn ln(self) -> Self;
 }
 
 pub trait HypTrigOps {
@@ -22,6 +22,12 @@ pub trait CheckedDiv<Rhs = Self> {
     fn checked_div(self, rhs: Rhs) -> Option<Self::Output>;
 }
 
+pub trait CheckedRem<Rhs = Self> {
+    type Output;
+
+    fn checked_rem(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
 pub trait CheckedShl<Rhs = Self> {
     type Output;
 
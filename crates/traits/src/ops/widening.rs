@@ -88,6 +88,30 @@ impl WideningMul for usize {
         let wide = (self as u128)
             .wrapping_mul(rhs as u128)
             .wrapping_add(add as u128);
-        (wide as usize, (wide >> 32) as usize)
+        (wide as usize, (wide >> 64) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_widening_mul() {
+        assert_eq!(
+            WideningMul::widening_mul(u8::MAX, u8::MAX, u8::MAX),
+            (0, u8::MAX)
+        );
+        assert_eq!(WideningMul::widening_mul(0x10u8, 0x10, 1), (1, 1));
+        assert_eq!(WideningMul::widening_mul(u64::MAX, 2, 0), (u64::MAX - 1, 1));
+        assert_eq!(
+            WideningMul::widening_mul(u128::MAX, u128::MAX, u128::MAX),
+            (0, u128::MAX)
+        );
+        assert_eq!(
+            WideningMul::widening_mul(usize::MAX, usize::MAX, usize::MAX),
+            (0, usize::MAX)
+        );
+        assert_eq!(WideningMul::widening_mul(usize::MAX, 2, 1), (usize::MAX, 1));
     }
 }
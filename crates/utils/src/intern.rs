@@ -6,8 +6,6 @@ use core::sync::atomic::{AtomicUsize, Ordering};
 use crate::linked::UnsyncLinked;
 use crate::static_assert;
 
-const CHUNK_SIZE: usize = 32;
-
 enum Find<T> {
     Exists(T),
     Dead(T),
@@ -66,30 +64,37 @@ impl InternId {
 }
 
 /// An optimized container that supports cross-thread, lock-free-ish
-pub struct Interner<T> {
-    inner: UnsyncLinked<[Interned<T>; CHUNK_SIZE]>,
+///
+/// Slots are allocated `CHUNK` at a time. Larger chunks mean fewer allocations, smaller ones mean
+/// less unused space for interners holding only a few values.
+pub struct Interner<T, const CHUNK: usize = 32> {
+    inner: UnsyncLinked<[Interned<T>; CHUNK]>,
 }
 
-impl<T> Interner<T>
+impl<T, const CHUNK: usize> Interner<T, CHUNK>
 where
     T: Clone + PartialEq,
 {
+    const NONZERO_CHUNK: () = assert!(CHUNK > 0, "Interner chunk size must be nonzero");
+
     #[must_use]
-    pub const fn new() -> Interner<T> {
+    pub const fn new() -> Interner<T, CHUNK> {
+        let () = Self::NONZERO_CHUNK;
         Interner {
             inner: UnsyncLinked::new(),
         }
     }
 
-    pub fn with_capacity(capacity: usize) -> Interner<T> {
+    pub fn with_capacity(capacity: usize) -> Interner<T, CHUNK> {
+        let () = Self::NONZERO_CHUNK;
         let list = UnsyncLinked::new();
-        for _ in 0..((capacity + CHUNK_SIZE - 1) / 32) {
-            list.push([(); CHUNK_SIZE].map(|_| Interned::new_uninit()));
+        for _ in 0..capacity.div_ceil(CHUNK) {
+            list.push([(); CHUNK].map(|_| Interned::new_uninit()));
         }
         Interner { inner: list }
     }
 
-    fn find<U>(list: &UnsyncLinked<[Interned<T>; CHUNK_SIZE]>, val: &U) -> Find<(usize, usize)>
+    fn find<U>(list: &UnsyncLinked<[Interned<T>; CHUNK]>, val: &U) -> Find<(usize, usize)>
     where
         U: ?Sized + PartialEq,
         T: Borrow<U>,
@@ -126,7 +131,7 @@ where
 
     #[inline(always)]
     fn offset_to_idx(offset: InternId) -> (usize, usize) {
-        (offset.0 / 32, offset.0 % 32)
+        (offset.0 / CHUNK, offset.0 % CHUNK)
     }
 
     /// Get or insert an item into the interner. Note that this takes `O(N)` time with respect
@@ -141,7 +146,7 @@ where
         InternId::from_usize(match find {
             Find::Exists((loc1, loc2)) => {
                 Self::incr_inner(&self.inner[loc1][loc2]);
-                loc1 * 32 + loc2
+                loc1 * CHUNK + loc2
             }
             // This can't race with below because they're guaranteed to act on different memory
             // locations. But maybe... it could race with itself?
@@ -149,16 +154,14 @@ where
                 Self::incr_inner(&self.inner[loc1][loc2]);
                 // SAFETY: Slot is dead, we're making it live, we are the only ones with access.
                 unsafe { self.inner[loc1][loc2].set_val(val.into()) };
-                loc1 * 32 + loc2
+                loc1 * CHUNK + loc2
             }
             Find::None => {
-                let len = self
-                    .inner
-                    .push([(); CHUNK_SIZE].map(|_| Interned::new_uninit()));
+                let len = self.inner.push([(); CHUNK].map(|_| Interned::new_uninit()));
                 Self::incr_inner(&self.inner[len - 1][0]);
                 // SAFETY: Slot is empty, we're making it live, we are the only ones with access
                 unsafe { self.inner[len - 1][0].set_val(val.into()) };
-                (len - 1) * 32
+                (len - 1) * CHUNK
             }
         })
     }
@@ -187,6 +190,27 @@ where
         Self::decr_inner(&self.inner[idx1][idx2])
     }
 
+    /// Get the number of slots allocated, whether or not they currently hold a live value. This
+    /// grows `CHUNK` slots at a time.
+    pub fn capacity(&self) -> usize {
+        self.inner.chunk_count() * CHUNK
+    }
+
+    /// Get the number of distinct values with at least one live reference. Values being added or
+    /// dropped by other threads at the same time may or may not be counted.
+    pub fn len(&self) -> usize {
+        self.inner
+            .iter()
+            .flatten()
+            .filter(|slot| slot.refs.load(Ordering::Acquire) > 0)
+            .count()
+    }
+
+    /// Check whether no values currently have a live reference
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     #[allow(dead_code)]
     pub fn refcount(&self, offset: InternId) -> usize {
         let (idx1, idx2) = Self::offset_to_idx(offset);
@@ -194,7 +218,7 @@ where
     }
 }
 
-impl<T: Clone + PartialEq> Default for Interner<T> {
+impl<T: Clone + PartialEq, const CHUNK: usize> Default for Interner<T, CHUNK> {
     fn default() -> Self {
         Self::new()
     }
@@ -257,6 +281,50 @@ mod tests {
 
         let pos1 = interner.add(-1);
         interner.decr(pos1.clone());
-        assert!(matches!(interner.try_get(pos1), None));
+        assert!(interner.try_get(pos1).is_none());
+    }
+
+    #[test]
+    fn test_len() {
+        let interner = Interner::<i32, 4>::new();
+        assert!(interner.is_empty());
+        assert_eq!(interner.capacity(), 0);
+
+        let ids = (0..6).map(|val| interner.add(val)).collect::<Vec<_>>();
+        interner.add(0);
+        assert_eq!(interner.len(), 6);
+        assert_eq!(interner.capacity(), 8);
+
+        // Dead slots still take up capacity, but aren't counted
+        interner.decr(ids[2].clone());
+        assert_eq!(interner.len(), 5);
+        assert_eq!(interner.capacity(), 8);
+        assert!(!interner.is_empty());
+    }
+
+    #[test]
+    fn test_small_chunk() {
+        let interner = Interner::<i32, 2>::new();
+
+        let ids = (0..7).map(|val| interner.add(val)).collect::<Vec<_>>();
+        for (val, id) in (0..7).zip(&ids) {
+            assert_eq!(interner.get(id.clone()), &val);
+            assert_eq!(interner.add(val), *id);
+        }
+        assert_eq!(interner.capacity(), 8);
+
+        // Dead slots in earlier chunks are reused before a new chunk is added
+        interner.decr(ids[1].clone());
+        interner.decr(ids[1].clone());
+        assert_eq!(interner.add(10), ids[1]);
+        assert_eq!(interner.add(11), InternId::from_usize(7));
+        assert_eq!(interner.capacity(), 8);
+        assert_eq!(interner.get(InternId::from_usize(7)), &11);
+    }
+
+    #[test]
+    fn test_with_capacity() {
+        let interner = Interner::<i32, 4>::with_capacity(9);
+        assert_eq!(interner.capacity(), 12);
     }
 }
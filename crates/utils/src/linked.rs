@@ -78,11 +78,18 @@ impl<T> UnsyncLinked<T> {
         (cur_node, len)
     }
 
-    #[allow(dead_code)]
+    /// Get the number of nodes in the list. This walks the whole list, so is `O(n)`.
     pub fn len(&self) -> usize {
         self.tail().1
     }
 
+    /// Get the number of chunks in a list of fixed-size blocks, such as the slot arrays of an
+    /// [`Interner`](crate::Interner). Each node holds one chunk, so this is the same walk as
+    /// [`UnsyncLinked::len`].
+    pub fn chunk_count(&self) -> usize {
+        self.len()
+    }
+
     #[allow(dead_code)]
     pub fn is_empty(&self) -> bool {
         self.head.load(Ordering::Acquire).is_null()
@@ -119,6 +126,8 @@ impl<T> UnsyncLinked<T> {
         Some(Node::get_val(cur_node))
     }
 
+    /// Iterate over the values in the list, in the order they were pushed. Values pushed while
+    /// iterating will still be reached, even by an iterator which has already returned `None`.
     pub fn iter(&self) -> Iter<'_, T> {
         Iter {
             cur: &self.head,
@@ -182,6 +191,29 @@ mod tests {
     use std::thread;
     use std::time::Duration;
 
+    #[test]
+    fn iter_len() {
+        let list = UnsyncLinked::new();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.iter().next(), None);
+
+        for i in 0..5 {
+            assert_eq!(list.push([i; 4]), i + 1);
+        }
+        assert!(!list.is_empty());
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.chunk_count(), 5);
+        assert!(list.iter().copied().eq((0..5).map(|i| [i; 4])));
+
+        // An iterator that reached the end sees values pushed afterwards
+        let mut iter = list.iter().skip(4);
+        assert_eq!(iter.next(), Some(&[4; 4]));
+        assert_eq!(iter.next(), None);
+        list.push([5; 4]);
+        assert_eq!(iter.next(), Some(&[5; 4]));
+    }
+
     #[test]
     fn index_add_thread() {
         let list = run_threaded(UnsyncLinked::new, |list, i| {
@@ -190,7 +222,7 @@ mod tests {
             } else {
                 let mut tries = 0;
                 loop {
-                    if list.get((i / 2) as usize).is_some() {
+                    if list.get(i / 2).is_some() {
                         break;
                     } else if tries > 100 {
                         panic!("{}", list.len())